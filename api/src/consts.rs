@@ -10,6 +10,9 @@ pub const TOKEN_DECIMALS: u8 = 6;
 /// One LUXITE token, denominated in indivisible units.
 pub const ONE_LUXITE: u64 = 10u64.pow(TOKEN_DECIMALS as u32); // 1_000_000
 
+/// The number of lamports in one SOL.
+pub const ONE_SOL: u64 = 1_000_000_000;
+
 /// The duration of one minute, in seconds.
 pub const ONE_MINUTE: i64 = 60;
 
@@ -61,6 +64,9 @@ pub const GRID: &[u8] = b"grid";
 /// The seed of the dimension account PDA.
 pub const DIMENSION: &[u8] = b"dimension";
 
+/// The seed of the dimension index account PDA.
+pub const DIMENSION_INDEX: &[u8] = b"dimension_index";
+
 /// The seed of the drill account PDA.
 pub const DRILL: &[u8] = b"drill";
 
@@ -76,9 +82,36 @@ pub const MINER: &[u8] = b"miner";
 /// The seed of the treasury account PDA.
 pub const TREASURY: &[u8] = b"treasury";
 
+/// The seed of the season account PDA.
+pub const SEASON: &[u8] = b"season";
+
 /// The seed of the stake account PDA.
 pub const STAKE: &[u8] = b"stake";
 
+/// The seed of the proposal account PDA.
+pub const PROPOSAL: &[u8] = b"proposal";
+
+/// The seed of the vote account PDA.
+pub const VOTE: &[u8] = b"vote";
+
+/// The seed of the vault account PDA.
+pub const VAULT: &[u8] = b"vault";
+
+/// The seed of the vault receipt mint PDA.
+pub const VAULT_MINT: &[u8] = b"vault_mint";
+
+/// The seed of the vault redemption account PDA.
+pub const VAULT_REDEMPTION: &[u8] = b"vault_redemption";
+
+/// The seed of the session key account PDA.
+pub const SESSION: &[u8] = b"session";
+
+/// The seed of the mining pool account PDA.
+pub const POOL: &[u8] = b"pool";
+
+/// The seed of the pool member account PDA.
+pub const POOL_MEMBER: &[u8] = b"pool_member";
+
 /// Program id for const pda derivations
 const PROGRAM_ID: [u8; 32] = unsafe { *(&crate::id() as *const Pubkey as *const [u8; 32]) };
 
@@ -99,18 +132,208 @@ pub const TREASURY_ADDRESS: Pubkey =
 /// The address of the treasury account.
 pub const TREASURY_BUMP: u8 = ed25519::derive_program_address(&[TREASURY], &PROGRAM_ID).1;
 
+/// Dimension ID passed to Scan to have the program assign the next sequential ID.
+pub const AUTO_DIMENSION_ID: u64 = u64::MAX;
+
+/// The first dimension ID assigned sequentially. Lower IDs may be chosen by the admin.
+pub const AUTO_DIMENSION_ID_START: u64 = 1 << 62;
+
+/// The number of dimension IDs recorded on each dimension index page.
+pub const DIMENSION_INDEX_PAGE_SIZE: usize = 32;
+
+/// The maximum richness score (a roll must exceed richness to hit).
+pub const MAX_RICHNESS: u64 = 999_999_999;
+
+/// Number of excavations after which a dimension loses half of its remaining richness headroom.
+pub const RICHNESS_HALF_DEPLETION_DEPTH: u64 = 50_000;
+
+/// Share of the accumulated depletion restored by a refine, in basis points.
+pub const REFINE_RESTORE_BPS: u64 = 5_000;
+
+/// Minimum time between paid refines of a dimension.
+pub const REFINE_COOLDOWN: i64 = ONE_WEEK;
+
 /// Minimum SOL deployed for full hit rate (0.1 SOL in lamports)
 pub const MIN_DEPLOYED_FOR_FULL_RATE: u64 = 100_000_000;
 
 /// The fee paid to bots if they checkpoint a user.
 pub const CHECKPOINT_FEE: u64 = 10_000; // 0.00001 SOL
 
+/// The bounty paid to bots per excavation closed with CloseMany.
+pub const CLOSE_BOUNTY: u64 = 10_000; // 0.00001 SOL
+
 /// Denominator for basis point calculations (100% = 10,000 bps).
 pub const DENOMINATOR_BPS: u64 = 10_000;
 
+/// Default share of a missed excavation's SOL rebated to its miners (5% = 500 bps).
+pub const MISS_REBATE_BPS: u64 = 500;
+
+/// Maximum miss rebate, in basis points.
+pub const MAX_MISS_REBATE_BPS: u64 = 5_000;
+
+/// Maximum LUXITE paid per SOL of miss rebate.
+pub const MAX_MISS_REBATE_LUXITE_RATE: u64 = ONE_LUXITE * 1_000;
+
+/// Minimum deploy cap other than 0 (unlimited), so a cap cannot block deploys.
+pub const MIN_DEPLOY_CAP: u64 = ONE_SOL;
+
+/// LUXITE curve splitting emissions linearly in deployed SOL.
+pub const LUXITE_CURVE_LINEAR: u64 = 0;
+
+/// LUXITE curve splitting emissions by the square root of deployed SOL.
+pub const LUXITE_CURVE_SQRT: u64 = 1;
+
 /// Fee charged on SOL deployment in basis points (1% = 100 bps).
 pub const DEPLOY_FEE_BPS: u64 = 100;
 
+/// Default share of a referee's deploy and scan fees paid to their referrer (10% = 1,000 bps).
+pub const REFERRAL_FEE_BPS: u64 = 1_000;
+
+/// XP earned per whole SOL deployed.
+pub const XP_PER_SOL_DEPLOYED: u64 = 10;
+
+/// XP earned per excavation hit.
+pub const XP_PER_HIT: u64 = 25;
+
+/// XP earned per dimension discovered.
+pub const XP_PER_DISCOVERY: u64 = 100;
+
+/// The number of navigator ranks.
+pub const RANK_COUNT: usize = 5;
+
+/// Minimum XP required for each rank.
+pub const RANK_XP_THRESHOLDS: [u64; RANK_COUNT] = [0, 1_000, 10_000, 50_000, 250_000];
+
+/// Deploy fee discount for each rank, in basis points of the deployed amount.
+pub const RANK_DEPLOY_FEE_DISCOUNT_BPS: [u64; RANK_COUNT] = [0, 5, 10, 20, 30];
+
+/// Badge awarded for the first excavation hit.
+pub const BADGE_FIRST_HIT: u64 = 1 << 0;
+
+/// Badge awarded for discovering a dimension below RICH_DISCOVERY_RICHNESS.
+pub const BADGE_RICH_DISCOVERY: u64 = 1 << 1;
+
+/// Badge awarded for deploying in DEPLOY_STREAK_BADGE_TICKS consecutive ticks.
+pub const BADGE_DEPLOY_STREAK: u64 = 1 << 2;
+
+/// Richness below which a discovery earns the rich discovery badge.
+pub const RICH_DISCOVERY_RICHNESS: u32 = 100_000_000;
+
+/// Consecutive deploy ticks required for the deploy streak badge.
+pub const DEPLOY_STREAK_BADGE_TICKS: u64 = 100;
+
+/// The number of ticks in an epoch (one season).
+pub const EPOCH_DURATION_TICKS: u64 = 10_080;
+
+/// Ticks after a season ends before it can be settled, so late checkpoints can still count.
+pub const SEASON_SETTLEMENT_DELAY_TICKS: u64 = 1_440;
+
+/// The number of navigators ranked on a season leaderboard.
+pub const SEASON_LEADERBOARD_SIZE: usize = 10;
+
+/// Share of the season prize pool paid to each leaderboard position, in basis points.
+pub const SEASON_PRIZE_BPS: [u64; SEASON_LEADERBOARD_SIZE] =
+    [3_000, 2_000, 1_500, 1_000, 800, 600, 400, 300, 250, 150];
+
+/// Season metric ranking navigators by SOL deployed.
+pub const SEASON_METRIC_DEPLOYED: u64 = 0;
+
+/// Season metric ranking navigators by LUXITE mined.
+pub const SEASON_METRIC_MINED: u64 = 1;
+
+/// The number of stake lock options.
+pub const STAKE_LOCK_OPTIONS: usize = 4;
+
+/// Lock duration for each stake lock option, in seconds (none, 30, 90, 365 days).
+pub const STAKE_LOCK_DURATIONS: [i64; STAKE_LOCK_OPTIONS] =
+    [0, 30 * ONE_DAY, 90 * ONE_DAY, 365 * ONE_DAY];
+
+/// Reward multiplier for each stake lock option, in basis points.
+pub const STAKE_LOCK_MULTIPLIERS_BPS: [u64; STAKE_LOCK_OPTIONS] = [10_000, 12_500, 15_000, 20_000];
+
+/// Default cooldown between a stake withdrawal and its completion, in seconds.
+pub const UNBONDING_PERIOD: i64 = 7 * ONE_DAY;
+
+/// Minimum cooldown that can be set for stake withdrawals, in seconds.
+pub const MIN_UNBONDING_PERIOD: i64 = ONE_DAY;
+
+/// Maximum cooldown the admin can set for stake withdrawals, in seconds.
+pub const MAX_UNBONDING_PERIOD: i64 = 30 * ONE_DAY;
+
+/// Default share of SOL revenue streamed to stakers instead of buybacks, in basis points.
+pub const STAKE_SOL_SHARE_BPS: u64 = 2_000;
+
+/// Default maximum LUXITE boost on mining rewards from staking, in basis points.
+pub const STAKE_BOOST_MAX_BPS: u64 = 2_500;
+
+/// Default staked balance earning half of the maximum mining boost.
+pub const STAKE_BOOST_HALF_BALANCE: u64 = ONE_LUXITE * 10_000;
+
+/// Maximum mining boost the admin can set, in basis points.
+pub const MAX_STAKE_BOOST_BPS: u64 = 10_000;
+
+/// Seconds a governance proposal is open for voting.
+pub const GOVERNANCE_VOTING_PERIOD: i64 = 3 * ONE_DAY;
+
+/// Seconds between the end of voting and when a passed proposal can be executed.
+pub const GOVERNANCE_TIMELOCK: i64 = 2 * ONE_DAY;
+
+/// Seconds after the timelock during which a passed proposal can be executed.
+pub const GOVERNANCE_EXECUTION_WINDOW: i64 = 7 * ONE_DAY;
+
+/// Seconds after initialization during which the admin can veto proposals.
+pub const GOVERNANCE_BOOTSTRAP_PERIOD: i64 = 180 * ONE_DAY;
+
+/// The staked balance required to create a proposal.
+pub const PROPOSAL_MIN_STAKE: u64 = ONE_LUXITE * 10_000;
+
+/// The share of total stake that must vote in favor for a proposal to pass, in basis points.
+pub const GOVERNANCE_QUORUM_BPS: u64 = 1_000;
+
+/// Governable config parameters.
+pub const PARAM_SCAN_FEE: u64 = 0;
+pub const PARAM_REFERRAL_FEE_BPS: u64 = 1;
+pub const PARAM_MISS_REBATE_BPS: u64 = 2;
+pub const PARAM_MISS_REBATE_LUXITE_RATE: u64 = 3;
+pub const PARAM_MAX_MINER_DEPLOY: u64 = 4;
+pub const PARAM_MAX_EXCAVATION_DEPLOY: u64 = 5;
+pub const PARAM_LUXITE_CURVE: u64 = 6;
+pub const PARAM_DEPLOY_CUTOFF_SLOTS: u64 = 7;
+pub const PARAM_LATE_DEPLOY_DECAY_BPS: u64 = 8;
+pub const PARAM_UNBONDING_PERIOD: u64 = 9;
+pub const PARAM_STAKE_SOL_SHARE_BPS: u64 = 10;
+pub const PARAM_STAKE_BOOST_MAX_BPS: u64 = 11;
+pub const PARAM_STAKE_BOOST_HALF_BALANCE: u64 = 12;
+
+/// Session key permission to deploy for the authority.
+pub const SESSION_PERMISSION_DEPLOY: u64 = 1 << 0;
+
+/// Session key permission to checkpoint for the authority.
+pub const SESSION_PERMISSION_CHECKPOINT: u64 = 1 << 1;
+
+/// The longest a session key can stay valid, in seconds.
+pub const MAX_SESSION_DURATION: i64 = ONE_WEEK;
+
+/// Checkpoint status of a miner's last excavation, as returned by ViewMinerRewards.
+pub const CHECKPOINT_STATUS_NONE: u64 = 0;
+pub const CHECKPOINT_STATUS_PENDING: u64 = 1;
+pub const CHECKPOINT_STATUS_READY: u64 = 2;
+pub const CHECKPOINT_STATUS_FORFEITED: u64 = 3;
+
+/// The largest share of a pool's idle balance it can deploy to one excavation, in basis points.
+/// Keeping part of the balance idle means pool shares always keep a price.
+pub const POOL_MAX_DEPLOY_BPS: u64 = 5_000;
+
+/// Slots a pool stays idle after a harvest before it can deploy again, so members always get
+/// a window to deposit and withdraw.
+pub const POOL_IDLE_SLOTS: u64 = 10 * ONE_MINUTE_SLOTS;
+
+/// The stake position ID held by the vault.
+pub const VAULT_STAKE_ID: u64 = 0;
+
+/// Fee on vault redemptions left in the vault for remaining holders, in basis points.
+pub const VAULT_REDEEM_FEE_BPS: u64 = 50;
+
 /// Amount paid to bots per transaction for auto-compounding staking yield, in lamports.
 pub const COMPOUND_FEE_PER_TRANSACTION: u64 = 7_000;
 
@@ -120,6 +343,12 @@ pub const ADMIN_FEE: u64 = 300; // 3%
 /// The fee paid to the admin for each dimension scan.
 pub const DIMENSION_SCAN_FEE: u64 = 100_000_000; // 0.1 SOL
 
+/// The maximum scan fee that can be set.
+pub const MAX_SCAN_FEE: u64 = 10_000_000_000; // 10 SOL
+
+/// The fee paid to the admin for each dimension refine.
+pub const DIMENSION_REFINE_FEE: u64 = 50_000_000; // 0.05 SOL
+
 /// The address to receive the admin fee.
 pub const ADMIN_FEE_COLLECTOR: Pubkey = pubkey!("Eb3BaMhYbcgcuFUnxtEkVzeFMpppLyFHQeEiM4XgGDJ5");
 
@@ -0,0 +1,129 @@
+use solana_program::program::invoke_signed;
+use steel::*;
+
+use crate::sdk;
+
+// Wrappers for invoking Local Universe from another program on behalf of a PDA authority.
+// `accounts` must hold every account of the wrapped instruction (see the matching builder in
+// `sdk`) and the Local Universe program. `signer_seeds` are the authority's seeds, including
+// its bump. The authority pays for deploys and rent, so it must be a PDA that holds no data.
+
+/// Deploys SOL from a PDA authority. Pass the authority's referrer (if any).
+pub fn deploy<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    dimension_id: u64,
+    tick_id: u64,
+    amount: u64,
+    min_total: u64,
+    referrer: Option<Pubkey>,
+) -> ProgramResult {
+    let ix = sdk::deploy(
+        *authority.key,
+        *authority.key,
+        dimension_id,
+        tick_id,
+        amount,
+        min_total,
+        referrer,
+        false,
+    );
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Checkpoints a PDA authority's miner, boosted by stake position `stake_id` (if any).
+pub fn checkpoint<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    dimension_id: u64,
+    excavation_id: u64,
+    stake_id: Option<u64>,
+) -> ProgramResult {
+    let ix = sdk::checkpoint(
+        *authority.key,
+        *authority.key,
+        dimension_id,
+        excavation_id,
+        stake_id,
+        false,
+    );
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Claims a PDA authority's mining LUXITE to the miner's recipient.
+pub fn claim_luxite<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    dimension_id: u64,
+    recipient: Pubkey,
+) -> ProgramResult {
+    let ix = sdk::claim_luxite(*authority.key, *authority.key, dimension_id, recipient);
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Claims a PDA authority's mining SOL to the miner's recipient.
+pub fn claim_sol<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    dimension_id: u64,
+    recipient: Pubkey,
+) -> ProgramResult {
+    let ix = sdk::claim_sol(*authority.key, *authority.key, dimension_id, recipient);
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Stakes LUXITE from a PDA authority's token account. `payer` funds rent and the compound fee
+/// reserve, and may be the authority itself.
+pub fn deposit<'info>(
+    authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    id: u64,
+    amount: u64,
+    compound_fee: u64,
+    lock: u64,
+) -> ProgramResult {
+    let ix = sdk::deposit(*authority.key, *payer.key, id, amount, compound_fee, lock);
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Starts unbonding LUXITE from a PDA authority's stake position.
+pub fn withdraw<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    id: u64,
+    amount: u64,
+) -> ProgramResult {
+    let ix = sdk::withdraw(*authority.key, id, amount);
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Transfers unbonded LUXITE to a PDA authority's token account.
+pub fn complete_withdraw<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    id: u64,
+) -> ProgramResult {
+    let ix = sdk::complete_withdraw(*authority.key, id);
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
+
+/// Claims staking yield from a PDA authority's stake position to the position's recipient.
+pub fn claim_yield<'info>(
+    authority: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+    id: u64,
+    amount: u64,
+    recipient: Pubkey,
+) -> ProgramResult {
+    let ix = sdk::claim_yield(*authority.key, *authority.key, id, amount, recipient);
+    invoke_signed(&ix, accounts, &[signer_seeds])
+}
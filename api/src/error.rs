@@ -8,6 +8,39 @@ pub enum LocalUniverseError {
 
     #[error("Not authorized")]
     NotAuthorized = 1,
+
+    #[error("Invalid dimension ID")]
+    InvalidDimensionId = 2,
+
+    #[error("Deploy cap exceeded")]
+    DeployCapExceeded = 3,
+
+    #[error("Deploy window closed")]
+    DeployWindowClosed = 4,
+
+    #[error("Stake is locked")]
+    StakeLocked = 5,
+
+    #[error("Stake is unbonding")]
+    StakeUnbonding = 6,
+
+    #[error("Session expired")]
+    SessionExpired = 7,
+
+    #[error("Session spend limit exceeded")]
+    SessionSpendLimit = 8,
+
+    #[error("Dimension already exists")]
+    DimensionExists = 9,
+
+    #[error("Refine on cooldown")]
+    RefineCooldown = 10,
+
+    #[error("Re-roll already pending")]
+    RerollPending = 11,
+
+    #[error("Tick has ended")]
+    TickEnded = 12,
 }
 
 error!(LocalUniverseError);
@@ -7,6 +7,12 @@ pub enum LocalUniverseEvent {
     Tick = 2,
     Excavate = 3,
     Buyback = 4,
+    Refine = 5,
+    SetReferrer = 6,
+    ReferralReward = 7,
+    ClaimReferral = 8,
+    RankUp = 9,
+    SettleSeason = 10,
 }
 
 #[repr(C)]
@@ -87,7 +93,7 @@ pub struct ExcavateEvent {
     /// The tick ID this result is for.
     pub tick_id: u64,
 
-    /// The richness score of the dimension.
+    /// The richness the excavation rolled against, after depletion and the low-deploy penalty.
     pub richness: u64,
 
     /// The total LUXITE distributed.
@@ -128,8 +134,137 @@ pub struct BuybackEvent {
     pub ts: i64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct RefineEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The dimension ID.
+    pub dimension_id: u64,
+
+    /// The wallet that refined.
+    pub refiner: Pubkey,
+
+    /// Whether the richness was re-rolled (1) or partly restored (0).
+    pub reroll: u64,
+
+    /// The base richness score after refining.
+    pub richness: u64,
+
+    /// The effective richness score after refining.
+    pub effective_richness: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct SetReferrerEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority that was referred.
+    pub authority: Pubkey,
+
+    /// The referrer.
+    pub referrer: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralRewardEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The referrer receiving the reward.
+    pub referrer: Pubkey,
+
+    /// The referee whose fee paid the reward.
+    pub referee: Pubkey,
+
+    /// The amount of SOL rewarded.
+    pub amount: u64,
+
+    /// The fee source (0 = deploy, 1 = scan).
+    pub source: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ClaimReferralEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The referrer claiming rewards.
+    pub authority: Pubkey,
+
+    /// The amount of SOL claimed.
+    pub amount: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct RankUpEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The navigator authority.
+    pub authority: Pubkey,
+
+    /// The new rank.
+    pub rank: u64,
+
+    /// The navigator's XP.
+    pub xp: u64,
+
+    /// The navigator's badges.
+    pub badges: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct SettleSeasonEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The season ID.
+    pub season_id: u64,
+
+    /// The LUXITE prize pool.
+    pub prize_pool: u64,
+
+    /// The LUXITE paid to winners.
+    pub paid: u64,
+
+    /// The number of winners paid.
+    pub winners: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
 event!(ScanEvent);
 event!(DeployEvent);
 event!(TickEvent);
 event!(ExcavateEvent);
 event!(BuybackEvent);
+event!(RefineEvent);
+event!(SetReferrerEvent);
+event!(ReferralRewardEvent);
+event!(ClaimReferralEvent);
+event!(RankUpEvent);
+event!(SettleSeasonEvent);
@@ -5,6 +5,7 @@ use steel::*;
 pub enum LocalUniverseInstruction {
     // Dimension
     Scan = 0,
+    Refine = 1,
 
     // Drill (global clock + processing)
     Tick = 10,
@@ -16,16 +17,58 @@ pub enum LocalUniverseInstruction {
     ClaimLUXITE = 22,
     ClaimSOL = 23,
     Close = 24,
+    SetMinerDelegate = 25,
+    ClaimAll = 26,
+    CloseMiner = 27,
+    CloseMany = 28,
 
     // Staker
     Deposit = 30,
     Withdraw = 31,
     ClaimYield = 32,
     CompoundYield = 33,
+    CompleteWithdraw = 34,
+    VaultDeposit = 35,
+    VaultRedeem = 36,
+    ClaimStakeSOL = 37,
+    SetStakeDelegate = 38,
+    PokeStake = 39,
 
     // Automation
     Automate = 40,
     ReloadSOL = 41,
+    CreateSession = 42,
+    RevokeSession = 43,
+
+    // Vault
+    CompleteRedeem = 44,
+
+    // Navigator
+    SetReferrer = 50,
+    ClaimReferral = 51,
+    CloseNavigator = 52,
+
+    // Season
+    SettleSeason = 60,
+
+    // Governance
+    Propose = 70,
+    CastVote = 71,
+    ExecuteProposal = 72,
+    VetoProposal = 73,
+
+    // Pool
+    CreatePool = 80,
+    SetPoolDimension = 81,
+    PoolDeposit = 82,
+    PoolWithdraw = 83,
+    PoolHarvest = 84,
+    PoolClaim = 85,
+
+    // View (read-only, via simulateTransaction)
+    ViewMinerRewards = 90,
+    ViewStakeYield = 91,
+    ViewExcavation = 92,
 
     // Admin
     Initialize = 100,
@@ -33,7 +76,19 @@ pub enum LocalUniverseInstruction {
     Buyback = 102,
     Wrap = 103,
     FundTreasury = 104,
-    SetScanFee = 105
+    SetScanFee = 105,
+    SetReferralFee = 106,
+    OpenSeason = 107,
+    SetMissRebate = 108,
+    SetDeployCaps = 109,
+    SetDeployWindow = 110,
+    SetUnbondingPeriod = 111,
+    InitializeVault = 112,
+    SetStakeSOLShare = 113,
+    SetStakeBoost = 114,
+
+    // Migration
+    Migrate = 120,
 }
 
 #[repr(C)]
@@ -42,6 +97,12 @@ pub struct Scan {
     pub dimension_id: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Refine {
+    pub reroll: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Tick {}
@@ -54,6 +115,7 @@ pub struct Excavate {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Deploy {
     pub amount: [u8; 8],
+    pub min_total: [u8; 8],
 }
 
 #[repr(C)]
@@ -72,28 +134,172 @@ pub struct ClaimSOL {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Close {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetMinerDelegate {
+    pub dimension_id: [u8; 8],
+    pub delegate: [u8; 32],
+    pub recipient: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimAll {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CloseMiner {
+    pub dimension_id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CloseMany {}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Deposit {
+    pub id: [u8; 8],
     pub amount: [u8; 8],
     pub compound_fee: [u8; 8],
+    pub lock: [u8; 8],
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Withdraw {
+    pub id: [u8; 8],
     pub amount: [u8; 8],
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ClaimYield {
+    pub id: [u8; 8],
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CompoundYield {
+    pub id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CompleteWithdraw {
+    pub id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct VaultDeposit {
     pub amount: [u8; 8],
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct CompoundYield {}
+pub struct VaultRedeem {
+    pub shares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CompleteRedeem {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimStakeSOL {
+    pub id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetStakeDelegate {
+    pub id: [u8; 8],
+    pub delegate: [u8; 32],
+    pub recipient: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PokeStake {
+    pub id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Propose {
+    pub stake_id: [u8; 8],
+    pub param: [u8; 8],
+    pub value: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CastVote {
+    pub proposal_id: [u8; 8],
+    pub stake_id: [u8; 8],
+    pub support: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ExecuteProposal {
+    pub proposal_id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct VetoProposal {
+    pub proposal_id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CreatePool {
+    pub id: [u8; 8],
+    pub dimension_id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetPoolDimension {
+    pub id: [u8; 8],
+    pub dimension_id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PoolDeposit {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PoolWithdraw {
+    pub shares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PoolHarvest {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PoolClaim {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ViewMinerRewards {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ViewStakeYield {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ViewExcavation {}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -111,6 +317,31 @@ pub struct ReloadSOL {
     pub dimension_id: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CreateSession {
+    pub key: [u8; 32],
+    pub duration: [u8; 8],
+    pub spend_limit: [u8; 8],
+    pub permissions: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct RevokeSession {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetReferrer {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimReferral {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CloseNavigator {}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Initialize {}
@@ -143,7 +374,75 @@ pub struct SetScanFee {
     pub scan_fee: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetReferralFee {
+    pub referral_fee_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SettleSeason {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct OpenSeason {
+    pub season_id: [u8; 8],
+    pub metric: [u8; 8],
+    pub prize_pool: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetMissRebate {
+    pub miss_rebate_bps: [u8; 8],
+    pub luxite_rate: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetDeployCaps {
+    pub max_miner_deploy: [u8; 8],
+    pub max_excavation_deploy: [u8; 8],
+    pub luxite_curve: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetDeployWindow {
+    pub deploy_cutoff_slots: [u8; 8],
+    pub late_deploy_decay_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetUnbondingPeriod {
+    pub unbonding_period: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct InitializeVault {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetStakeSOLShare {
+    pub stake_sol_share_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetStakeBoost {
+    pub max_boost_bps: [u8; 8],
+    pub half_balance: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Migrate {}
+
 instruction!(LocalUniverseInstruction, Scan);
+instruction!(LocalUniverseInstruction, Refine);
 instruction!(LocalUniverseInstruction, Tick);
 instruction!(LocalUniverseInstruction, Excavate);
 instruction!(LocalUniverseInstruction, Deploy);
@@ -151,15 +450,55 @@ instruction!(LocalUniverseInstruction, Checkpoint);
 instruction!(LocalUniverseInstruction, ClaimLUXITE);
 instruction!(LocalUniverseInstruction, ClaimSOL);
 instruction!(LocalUniverseInstruction, Close);
+instruction!(LocalUniverseInstruction, SetMinerDelegate);
+instruction!(LocalUniverseInstruction, ClaimAll);
+instruction!(LocalUniverseInstruction, CloseMiner);
+instruction!(LocalUniverseInstruction, CloseMany);
 instruction!(LocalUniverseInstruction, Deposit);
 instruction!(LocalUniverseInstruction, Withdraw);
 instruction!(LocalUniverseInstruction, ClaimYield);
 instruction!(LocalUniverseInstruction, CompoundYield);
+instruction!(LocalUniverseInstruction, CompleteWithdraw);
+instruction!(LocalUniverseInstruction, VaultDeposit);
+instruction!(LocalUniverseInstruction, VaultRedeem);
+instruction!(LocalUniverseInstruction, CompleteRedeem);
+instruction!(LocalUniverseInstruction, ClaimStakeSOL);
+instruction!(LocalUniverseInstruction, SetStakeDelegate);
+instruction!(LocalUniverseInstruction, PokeStake);
 instruction!(LocalUniverseInstruction, Automate);
 instruction!(LocalUniverseInstruction, ReloadSOL);
+instruction!(LocalUniverseInstruction, CreateSession);
+instruction!(LocalUniverseInstruction, RevokeSession);
 instruction!(LocalUniverseInstruction, Initialize);
 instruction!(LocalUniverseInstruction, SetAdmin);
 instruction!(LocalUniverseInstruction, Wrap);
 instruction!(LocalUniverseInstruction, Buyback);
 instruction!(LocalUniverseInstruction, FundTreasury);
 instruction!(LocalUniverseInstruction, SetScanFee);
+instruction!(LocalUniverseInstruction, SetReferrer);
+instruction!(LocalUniverseInstruction, ClaimReferral);
+instruction!(LocalUniverseInstruction, CloseNavigator);
+instruction!(LocalUniverseInstruction, SetReferralFee);
+instruction!(LocalUniverseInstruction, SettleSeason);
+instruction!(LocalUniverseInstruction, OpenSeason);
+instruction!(LocalUniverseInstruction, SetMissRebate);
+instruction!(LocalUniverseInstruction, SetDeployCaps);
+instruction!(LocalUniverseInstruction, SetDeployWindow);
+instruction!(LocalUniverseInstruction, SetUnbondingPeriod);
+instruction!(LocalUniverseInstruction, InitializeVault);
+instruction!(LocalUniverseInstruction, SetStakeSOLShare);
+instruction!(LocalUniverseInstruction, SetStakeBoost);
+instruction!(LocalUniverseInstruction, Propose);
+instruction!(LocalUniverseInstruction, CastVote);
+instruction!(LocalUniverseInstruction, ExecuteProposal);
+instruction!(LocalUniverseInstruction, VetoProposal);
+instruction!(LocalUniverseInstruction, CreatePool);
+instruction!(LocalUniverseInstruction, SetPoolDimension);
+instruction!(LocalUniverseInstruction, PoolDeposit);
+instruction!(LocalUniverseInstruction, PoolWithdraw);
+instruction!(LocalUniverseInstruction, PoolHarvest);
+instruction!(LocalUniverseInstruction, PoolClaim);
+instruction!(LocalUniverseInstruction, ViewMinerRewards);
+instruction!(LocalUniverseInstruction, ViewStakeYield);
+instruction!(LocalUniverseInstruction, ViewExcavation);
+instruction!(LocalUniverseInstruction, Migrate);
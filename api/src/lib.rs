@@ -1,9 +1,11 @@
 pub mod consts;
+pub mod cpi;
 pub mod error;
 pub mod event;
 pub mod instruction;
 pub mod sdk;
 pub mod state;
+pub mod view;
 
 pub mod prelude {
     pub use crate::consts::*;
@@ -12,6 +14,7 @@ pub mod prelude {
     pub use crate::instruction::*;
     pub use crate::sdk::*;
     pub use crate::state::*;
+    pub use crate::view::*;
 }
 
 use steel::*;
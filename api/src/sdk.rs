@@ -4,26 +4,81 @@ use steel::*;
 
 use crate::{consts::*, instruction::*, state::*};
 
-/// Builds a Scan instruction to discover a new dimension.
-pub fn scan(signer: Pubkey, dimension_id: u64) -> Instruction {
+/// Builds a Scan instruction to discover a new dimension with a manually chosen ID (admin only).
+/// `dimension_count` is the current `Config.dimension_count`, used to locate the index page.
+pub fn scan(
+    signer: Pubkey,
+    dimension_id: u64,
+    dimension_count: u64,
+    referrer: Option<Pubkey>,
+) -> Instruction {
     let config_address = config_pda().0;
     let dimension_address = dimension_pda(dimension_id).0;
     let drill_address = drill_pda(dimension_id).0;
     let navigator_address = navigator_pda(signer).0;
+    let dimension_index_address = dimension_index_pda(DimensionIndex::page_of(dimension_count)).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(dimension_address, false),
+        AccountMeta::new(drill_address, false),
+        AccountMeta::new(navigator_address, false),
+        AccountMeta::new(dimension_index_address, false),
+        AccountMeta::new(ADMIN_FEE_COLLECTOR, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
+    ];
+    if let Some(referrer) = referrer {
+        accounts.push(AccountMeta::new(navigator_pda(referrer).0, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: Scan {
+            dimension_id: dimension_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a Scan instruction that is assigned the next sequential dimension ID.
+/// `next_dimension_id` and `dimension_count` are read from the current Config.
+pub fn scan_auto(
+    signer: Pubkey,
+    next_dimension_id: u64,
+    dimension_count: u64,
+    referrer: Option<Pubkey>,
+) -> Instruction {
+    let mut ix = scan(signer, next_dimension_id, dimension_count, referrer);
+    ix.data = Scan {
+        dimension_id: AUTO_DIMENSION_ID.to_le_bytes(),
+    }
+    .to_bytes();
+    ix
+}
+
+/// Builds a Refine instruction to restore or re-roll a dimension's richness. A re-roll is
+/// committed by the first call and applied by the next Excavate, or by a second call once the
+/// committed slot has passed.
+pub fn refine(signer: Pubkey, dimension_id: u64, reroll: bool) -> Instruction {
+    let config_address = config_pda().0;
+    let grid_address = grid_pda().0;
+    let dimension_address = dimension_pda(dimension_id).0;
+    let drill_address = drill_pda(dimension_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(grid_address, false),
             AccountMeta::new(dimension_address, false),
-            AccountMeta::new(drill_address, false),
-            AccountMeta::new(navigator_address, false),
+            AccountMeta::new_readonly(drill_address, false),
             AccountMeta::new(ADMIN_FEE_COLLECTOR, false),
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
         ],
-        data: Scan {
-            dimension_id: dimension_id.to_le_bytes(),
+        data: Refine {
+            reroll: (reroll as u64).to_le_bytes(),
         }
         .to_bytes(),
     }
@@ -32,14 +87,23 @@ pub fn scan(signer: Pubkey, dimension_id: u64) -> Instruction {
 /// Builds a Deploy instruction.
 /// If automation exists, pass authority separately from signer.
 /// If no automation, signer and authority should be the same.
+/// Pass the authority's referrer (if any) so the referral share can be paid.
+/// Set `session` when `signer` is a session key of the authority.
+/// A non-zero `min_total` makes the deploy conditional: it is refunded at checkpoint if the
+/// excavation's total deployed ends below `min_total`. It is only honored when `signer` is
+/// the authority; delegated deploys keep the threshold the authority set for the excavation.
 pub fn deploy(
     signer: Pubkey,
     authority: Pubkey,
     dimension_id: u64,
     tick_id: u64,
     amount: u64,
+    min_total: u64,
+    referrer: Option<Pubkey>,
+    session: bool,
 ) -> Instruction {
     let automation_address = automation_pda(authority, dimension_id).0;
+    let config = config_pda().0;
     let grid = grid_pda().0;
     let dimension = dimension_pda(dimension_id).0;
     let drill = drill_pda(dimension_id).0;
@@ -48,23 +112,33 @@ pub fn deploy(
     let navigator = navigator_pda(authority).0;
     let treasury = treasury_pda().0;
 
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+        AccountMeta::new(automation_address, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(grid, false),
+        AccountMeta::new_readonly(dimension, false),
+        AccountMeta::new(drill, false),
+        AccountMeta::new(excavation, false),
+        AccountMeta::new(miner, false),
+        AccountMeta::new(navigator, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    if session {
+        accounts.push(AccountMeta::new(session_key_pda(authority, signer).0, false));
+    }
+    if let Some(referrer) = referrer {
+        accounts.push(AccountMeta::new(navigator_pda(referrer).0, false));
+    }
+
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(authority, false),
-            AccountMeta::new(automation_address, false),
-            AccountMeta::new_readonly(grid, false),
-            AccountMeta::new_readonly(dimension, false),
-            AccountMeta::new(drill, false),
-            AccountMeta::new(excavation, false),
-            AccountMeta::new(miner, false),
-            AccountMeta::new(navigator, false),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        accounts,
         data: Deploy {
             amount: amount.to_le_bytes(),
+            min_total: min_total.to_le_bytes(),
         }
         .to_bytes(),
     }
@@ -85,6 +159,7 @@ pub fn tick(signer: Pubkey) -> Instruction {
 
 /// Builds an Excavate instruction to process the previous tick's excavation.
 pub fn excavate(signer: Pubkey, dimension_id: u64, previous_tick_id: u64) -> Instruction {
+    let config_address = config_pda().0;
     let grid_address = grid_pda().0;
     let dimension_address = dimension_pda(dimension_id).0;
     let drill_address = drill_pda(dimension_id).0;
@@ -95,8 +170,9 @@ pub fn excavate(signer: Pubkey, dimension_id: u64, previous_tick_id: u64) -> Ins
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new_readonly(grid_address, false),
-            AccountMeta::new_readonly(dimension_address, false),
+            AccountMeta::new(dimension_address, false),
             AccountMeta::new(drill_address, false),
             AccountMeta::new(excavation_address, false),
             AccountMeta::new(treasury_address, false),
@@ -108,32 +184,51 @@ pub fn excavate(signer: Pubkey, dimension_id: u64, previous_tick_id: u64) -> Ins
 
 /// Builds a Checkpoint instruction to claim rewards after excavation is processed.
 /// Can be called by the miner themselves, or by a bot (who earns checkpoint fee if in window).
+/// `stake_id` selects a stake position of the authority to boost the LUXITE share of a hit.
+/// Set `session` when `signer` is a session key of the authority.
 pub fn checkpoint(
     signer: Pubkey,
     authority: Pubkey,
     dimension_id: u64,
     excavation_id: u64,
+    stake_id: Option<u64>,
+    session: bool,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let grid_address = grid_pda().0;
     let excavation_address = excavation_pda(dimension_id, excavation_id).0;
     let miner_address = miner_pda(dimension_id, authority).0;
+    let navigator_address = navigator_pda(authority).0;
+    let season_address = season_pda(excavation_id / EPOCH_DURATION_TICKS).0;
     let treasury_address = treasury_pda().0;
 
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new_readonly(grid_address, false),
+        AccountMeta::new(excavation_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(navigator_address, false),
+        AccountMeta::new(season_address, false),
+        AccountMeta::new(treasury_address, false),
+    ];
+    if session {
+        accounts.push(AccountMeta::new_readonly(session_key_pda(authority, signer).0, false));
+    }
+    if let Some(stake_id) = stake_id {
+        accounts.push(AccountMeta::new_readonly(stake_pda(authority, stake_id).0, false));
+    }
+
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new_readonly(grid_address, false),
-            AccountMeta::new(excavation_address, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new(treasury_address, false),
-        ],
+        accounts,
         data: Checkpoint {}.to_bytes(),
     }
 }
 
 /// Builds a Close instruction to close an expired excavation and reclaim rent.
 pub fn close(signer: Pubkey, dimension_id: u64, tick_id: u64, rent_payer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
     let grid_address = grid_pda().0;
     let excavation_address = excavation_pda(dimension_id, tick_id).0;
     let treasury_address = treasury_pda().0;
@@ -142,6 +237,7 @@ pub fn close(signer: Pubkey, dimension_id: u64, tick_id: u64, rent_payer: Pubkey
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new_readonly(grid_address, false),
             AccountMeta::new(excavation_address, false),
             AccountMeta::new(rent_payer, false),
@@ -151,13 +247,44 @@ pub fn close(signer: Pubkey, dimension_id: u64, tick_id: u64, rent_payer: Pubkey
     }
 }
 
-/// Builds a ClaimLUXITE instruction to claim pending mining rewards.
-pub fn claim_luxite(signer: Pubkey, dimension_id: u64) -> Instruction {
-    let miner_address = miner_pda(dimension_id, signer).0;
-    let navigator_address = navigator_pda(signer).0;
+/// Builds a CloseMany instruction to close expired or stale excavations for a bounty.
+/// `excavations` are (dimension ID, tick ID, rent payer) triples.
+pub fn close_many(signer: Pubkey, excavations: &[(u64, u64, Pubkey)]) -> Instruction {
+    let config_address = config_pda().0;
+    let grid_address = grid_pda().0;
+    let treasury_address = treasury_pda().0;
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new_readonly(grid_address, false),
+        AccountMeta::new(treasury_address, false),
+    ];
+    for (dimension_id, tick_id, rent_payer) in excavations {
+        accounts.push(AccountMeta::new(excavation_pda(*dimension_id, *tick_id).0, false));
+        accounts.push(AccountMeta::new(*rent_payer, false));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: CloseMany {}.to_bytes(),
+    }
+}
+
+/// Builds a ClaimLUXITE instruction to claim pending LUXITE rewards. `signer` is the authority
+/// or its claim delegate; `recipient` is the miner's recipient (the authority if unset).
+pub fn claim_luxite(
+    signer: Pubkey,
+    authority: Pubkey,
+    dimension_id: u64,
+    recipient: Pubkey,
+) -> Instruction {
+    let miner_address = miner_pda(dimension_id, authority).0;
+    let navigator_address = navigator_pda(authority).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let recipient_address = get_associated_token_address(&recipient, &MINT_ADDRESS);
 
     Instruction {
         program_id: crate::ID,
@@ -172,15 +299,22 @@ pub fn claim_luxite(signer: Pubkey, dimension_id: u64) -> Instruction {
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(recipient, false),
         ],
         data: ClaimLUXITE {}.to_bytes(),
     }
 }
 
-/// Builds a ClaimSOL instruction to claim pending SOL rewards.
-pub fn claim_sol(signer: Pubkey, dimension_id: u64) -> Instruction {
-    let miner_address = miner_pda(dimension_id, signer).0;
-    let navigator_address = navigator_pda(signer).0;
+/// Builds a ClaimSOL instruction to claim pending SOL rewards. `signer` is the authority or its
+/// claim delegate; `recipient` is the miner's recipient (the authority if unset).
+pub fn claim_sol(
+    signer: Pubkey,
+    authority: Pubkey,
+    dimension_id: u64,
+    recipient: Pubkey,
+) -> Instruction {
+    let miner_address = miner_pda(dimension_id, authority).0;
+    let navigator_address = navigator_pda(authority).0;
 
     Instruction {
         program_id: crate::ID,
@@ -188,15 +322,106 @@ pub fn claim_sol(signer: Pubkey, dimension_id: u64) -> Instruction {
             AccountMeta::new(signer, true),
             AccountMeta::new(miner_address, false),
             AccountMeta::new(navigator_address, false),
+            AccountMeta::new(recipient, false),
         ],
         data: ClaimSOL {}.to_bytes(),
     }
 }
 
+/// Builds a ClaimAll instruction to claim SOL and LUXITE from the authority's miners on
+/// `dimension_ids`, plus optionally the yield and SOL revenue of stake position `stake_id`.
+/// `signer` is the authority or its claim delegate; every account must pay out to `recipient`.
+pub fn claim_all(
+    signer: Pubkey,
+    authority: Pubkey,
+    dimension_ids: &[u64],
+    stake_id: Option<u64>,
+    recipient: Pubkey,
+) -> Instruction {
+    let navigator_address = navigator_pda(authority).0;
+    let recipient_tokens_address = get_associated_token_address(&recipient, &MINT_ADDRESS);
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(navigator_address, false),
+        AccountMeta::new(recipient, false),
+        AccountMeta::new_readonly(MINT_ADDRESS, false),
+        AccountMeta::new(recipient_tokens_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ];
+    for dimension_id in dimension_ids {
+        accounts.push(AccountMeta::new(miner_pda(*dimension_id, authority).0, false));
+    }
+    if let Some(stake_id) = stake_id {
+        accounts.push(AccountMeta::new(stake_pda(authority, stake_id).0, false));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ClaimAll {}.to_bytes(),
+    }
+}
+
+/// Builds a CloseMiner instruction to reclaim a checkpointed miner's rent and checkpoint fee.
+pub fn close_miner(signer: Pubkey, dimension_id: u64) -> Instruction {
+    let miner_address = miner_pda(dimension_id, signer).0;
+    let navigator_address = navigator_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(navigator_address, false),
+        ],
+        data: CloseMiner {
+            dimension_id: dimension_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SetMinerDelegate instruction. Pass the default pubkey to clear either override.
+pub fn set_miner_delegate(
+    signer: Pubkey,
+    dimension_id: u64,
+    delegate: Pubkey,
+    recipient: Pubkey,
+) -> Instruction {
+    let miner_address = miner_pda(dimension_id, signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+        ],
+        data: SetMinerDelegate {
+            dimension_id: dimension_id.to_le_bytes(),
+            delegate: delegate.to_bytes(),
+            recipient: recipient.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
 /// Builds a Deposit instruction to stake LUXITE.
-pub fn deposit(signer: Pubkey, payer: Pubkey, amount: u64, compound_fee: u64) -> Instruction {
+/// `lock` is an index into STAKE_LOCK_DURATIONS (0 = no lock).
+pub fn deposit(
+    signer: Pubkey,
+    payer: Pubkey,
+    id: u64,
+    amount: u64,
+    compound_fee: u64,
+    lock: u64,
+) -> Instruction {
     let mint_address = MINT_ADDRESS;
-    let stake_address = stake_pda(signer).0;
+    let stake_address = stake_pda(signer, id).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
     let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
     let treasury_address = treasury_pda().0;
@@ -215,20 +440,42 @@ pub fn deposit(signer: Pubkey, payer: Pubkey, amount: u64, compound_fee: u64) ->
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
         data: Deposit {
+            id: id.to_le_bytes(),
             amount: amount.to_le_bytes(),
             compound_fee: compound_fee.to_le_bytes(),
+            lock: lock.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a Withdraw instruction to start unbonding staked LUXITE.
+pub fn withdraw(signer: Pubkey, id: u64, amount: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let stake_address = stake_pda(signer, id).0;
+    let treasury_address = treasury_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+        ],
+        data: Withdraw {
+            id: id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-/// Builds a Withdraw instruction to unstake LUXITE.
-pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
-    let stake_address = stake_pda(signer).0;
+/// Builds a CompleteWithdraw instruction to transfer out unbonded LUXITE.
+pub fn complete_withdraw(signer: Pubkey, id: u64) -> Instruction {
+    let stake_address = stake_pda(signer, id).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
     let mint_address = MINT_ADDRESS;
     let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
-    let treasury_address = treasury_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -237,23 +484,29 @@ pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new(recipient_address, false),
             AccountMeta::new(stake_address, false),
             AccountMeta::new(stake_tokens_address, false),
-            AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
-        data: Withdraw {
-            amount: amount.to_le_bytes(),
+        data: CompleteWithdraw {
+            id: id.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-/// Builds a ClaimYield instruction to claim staking rewards.
-pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
-    let stake_address = stake_pda(signer).0;
+/// Builds a ClaimYield instruction to claim staking rewards. `signer` is the authority or its
+/// claim delegate; `recipient` is the position's recipient (the authority if unset).
+pub fn claim_yield(
+    signer: Pubkey,
+    authority: Pubkey,
+    id: u64,
+    amount: u64,
+    recipient: Pubkey,
+) -> Instruction {
+    let stake_address = stake_pda(authority, id).0;
     let mint_address = MINT_ADDRESS;
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let recipient_address = get_associated_token_address(&recipient, &MINT_ADDRESS);
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
     Instruction {
@@ -268,17 +521,37 @@ pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(recipient, false),
         ],
         data: ClaimYield {
+            id: id.to_le_bytes(),
             amount: amount.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
+/// Builds a PokeStake instruction to drop the multiplier of a position whose lock expired.
+pub fn poke_stake(signer: Pubkey, authority: Pubkey, id: u64) -> Instruction {
+    let stake_address = stake_pda(authority, id).0;
+    let treasury_address = treasury_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+        ],
+        data: PokeStake {
+            id: id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
 /// Builds a CompoundYield instruction for bots to auto-compound staking rewards.
-pub fn compound_yield(signer: Pubkey, authority: Pubkey) -> Instruction {
-    let stake_address = stake_pda(authority).0;
+pub fn compound_yield(signer: Pubkey, authority: Pubkey, id: u64) -> Instruction {
+    let stake_address = stake_pda(authority, id).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
@@ -294,74 +567,228 @@ pub fn compound_yield(signer: Pubkey, authority: Pubkey) -> Instruction {
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
         ],
-        data: CompoundYield {}.to_bytes(),
+        data: CompoundYield {
+            id: id.to_le_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
-/// Builds a Buyback instruction to swap SOL for LUXITE and burn.
-pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8]) -> Instruction {
-    let config_address = config_pda().0;
-    let grid_address = grid_pda().0;
+/// Builds a ClaimStakeSOL instruction to claim SOL revenue earned by a stake position.
+/// `signer` is the authority or its claim delegate; `recipient` is the position's recipient.
+pub fn claim_stake_sol(
+    signer: Pubkey,
+    authority: Pubkey,
+    id: u64,
+    recipient: Pubkey,
+) -> Instruction {
+    let stake_address = stake_pda(authority, id).0;
     let treasury_address = treasury_pda().0;
-    let treasury_luxite_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let treasury_sol_address = get_associated_token_address(&treasury_address, &SOL_MINT);
-    let admin_fee_address = get_associated_token_address(&ADMIN_FEE_COLLECTOR, &SOL_MINT);
-
-    let mut accounts = vec![
-        AccountMeta::new(signer, true),
-        AccountMeta::new(grid_address, false),
-        AccountMeta::new(config_address, false),
-        AccountMeta::new(MINT_ADDRESS, false),
-        AccountMeta::new(treasury_address, false),
-        AccountMeta::new(treasury_luxite_address, false),
-        AccountMeta::new(treasury_sol_address, false),
-        AccountMeta::new(admin_fee_address, false),
-        AccountMeta::new_readonly(spl_token::ID, false),
-        AccountMeta::new_readonly(crate::ID, false),
-    ];
-
-    for account in swap_accounts.iter() {
-        let mut acc_clone = account.clone();
-        acc_clone.is_signer = false;
-        accounts.push(acc_clone);
-    }
-
-    let mut data = Buyback {}.to_bytes();
-    data.extend_from_slice(swap_data);
-
     Instruction {
         program_id: crate::ID,
-        accounts,
-        data,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(recipient, false),
+        ],
+        data: ClaimStakeSOL {
+            id: id.to_le_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
-/// Builds an Automate instruction to setup automation for a dimension.
-/// Pass executor = Pubkey::default() to close automation and withdraw balance.
-pub fn automate(
+/// Builds a SetStakeDelegate instruction. Pass the default pubkey to clear either override.
+pub fn set_stake_delegate(
     signer: Pubkey,
-    executor: Pubkey,
-    dimension_id: u64,
-    amount: u64,
-    deposit: u64,
-    fee: u64,
-    reload: bool,
+    id: u64,
+    delegate: Pubkey,
+    recipient: Pubkey,
 ) -> Instruction {
-    let automation_address = automation_pda(signer, dimension_id).0;
-    let miner_address = miner_pda(dimension_id, signer).0;
-    let dimension_address = dimension_pda(dimension_id).0;
-
+    let stake_address = stake_pda(signer, id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(automation_address, false),
-            AccountMeta::new_readonly(executor, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new_readonly(dimension_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(stake_address, false),
         ],
-        data: Automate {
+        data: SetStakeDelegate {
+            id: id.to_le_bytes(),
+            delegate: delegate.to_bytes(),
+            recipient: recipient.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a VaultDeposit instruction to stake LUXITE through the vault for receipt tokens.
+pub fn vault_deposit(signer: Pubkey, amount: u64) -> Instruction {
+    let mint_address = MINT_ADDRESS;
+    let token_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let vault_address = vault_pda().0;
+    let vault_mint_address = vault_mint_pda().0;
+    let receipt_address = get_associated_token_address(&signer, &vault_mint_address);
+    let stake_address = stake_pda(vault_address, VAULT_STAKE_ID).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(mint_address, false),
+            AccountMeta::new(token_address, false),
+            AccountMeta::new(vault_address, false),
+            AccountMeta::new(vault_mint_address, false),
+            AccountMeta::new(receipt_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: VaultDeposit {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a VaultRedeem instruction to burn receipt tokens and start unbonding the LUXITE
+/// backing them. Complete the redemption with `complete_redeem` once unbonding has passed.
+pub fn vault_redeem(signer: Pubkey, shares: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let vault_address = vault_pda().0;
+    let vault_mint_address = vault_mint_pda().0;
+    let receipt_address = get_associated_token_address(&signer, &vault_mint_address);
+    let redemption_address = vault_redemption_pda(signer).0;
+    let stake_address = stake_pda(vault_address, VAULT_STAKE_ID).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(vault_address, false),
+            AccountMeta::new(vault_mint_address, false),
+            AccountMeta::new(receipt_address, false),
+            AccountMeta::new(redemption_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: VaultRedeem {
+            shares: shares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a CompleteRedeem instruction to withdraw unbonded LUXITE from a vault redemption.
+pub fn complete_redeem(signer: Pubkey) -> Instruction {
+    let token_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let vault_address = vault_pda().0;
+    let redemption_address = vault_redemption_pda(signer).0;
+    let stake_address = stake_pda(vault_address, VAULT_STAKE_ID).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(token_address, false),
+            AccountMeta::new(vault_address, false),
+            AccountMeta::new(redemption_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: CompleteRedeem {}.to_bytes(),
+    }
+}
+
+/// Lists the stake position addresses of an authority for position IDs `0..count`.
+pub fn stake_pdas(authority: Pubkey, count: u64) -> Vec<Pubkey> {
+    (0..count).map(|id| stake_pda(authority, id).0).collect()
+}
+
+/// Builds a Buyback instruction to swap SOL for LUXITE and burn.
+pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8]) -> Instruction {
+    let config_address = config_pda().0;
+    let grid_address = grid_pda().0;
+    let treasury_address = treasury_pda().0;
+    let treasury_luxite_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    let treasury_sol_address = get_associated_token_address(&treasury_address, &SOL_MINT);
+    let admin_fee_address = get_associated_token_address(&ADMIN_FEE_COLLECTOR, &SOL_MINT);
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(grid_address, false),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_luxite_address, false),
+        AccountMeta::new(treasury_sol_address, false),
+        AccountMeta::new(admin_fee_address, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    for account in swap_accounts.iter() {
+        let mut acc_clone = account.clone();
+        acc_clone.is_signer = false;
+        accounts.push(acc_clone);
+    }
+
+    let mut data = Buyback {}.to_bytes();
+    data.extend_from_slice(swap_data);
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Builds an Automate instruction to setup automation for a dimension.
+/// Pass executor = Pubkey::default() to close automation and withdraw balance.
+pub fn automate(
+    signer: Pubkey,
+    executor: Pubkey,
+    dimension_id: u64,
+    amount: u64,
+    deposit: u64,
+    fee: u64,
+    reload: bool,
+) -> Instruction {
+    let automation_address = automation_pda(signer, dimension_id).0;
+    let miner_address = miner_pda(dimension_id, signer).0;
+    let navigator_address = navigator_pda(signer).0;
+    let dimension_address = dimension_pda(dimension_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(automation_address, false),
+            AccountMeta::new_readonly(executor, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(navigator_address, false),
+            AccountMeta::new_readonly(dimension_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: Automate {
             dimension_id: dimension_id.to_le_bytes(),
             amount: amount.to_le_bytes(),
             deposit: deposit.to_le_bytes(),
@@ -391,6 +818,46 @@ pub fn reload_sol(signer: Pubkey, authority: Pubkey, dimension_id: u64) -> Instr
     }
 }
 
+/// Builds a CreateSession instruction. `key` can deploy (up to `spend_limit`, escrowed from the
+/// signer) and/or checkpoint for the signer for `duration` seconds, per `permissions`.
+pub fn create_session(
+    signer: Pubkey,
+    key: Pubkey,
+    duration: i64,
+    spend_limit: u64,
+    permissions: u64,
+) -> Instruction {
+    let session_address = session_key_pda(signer, key).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(session_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CreateSession {
+            key: key.to_bytes(),
+            duration: duration.to_le_bytes(),
+            spend_limit: spend_limit.to_le_bytes(),
+            permissions: permissions.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a RevokeSession instruction to close a session key and reclaim its escrow.
+pub fn revoke_session(signer: Pubkey, key: Pubkey) -> Instruction {
+    let session_address = session_key_pda(signer, key).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(session_address, false),
+        ],
+        data: RevokeSession {}.to_bytes(),
+    }
+}
+
 /// Builds an Initialize instruction (admin only).
 pub fn initialize(signer: Pubkey) -> Instruction {
     let config_address = config_pda().0;
@@ -488,3 +955,544 @@ pub fn set_scan_fee(signer: Pubkey, scan_fee: u64) -> Instruction {
         .to_bytes(),
     }
 }
+
+/// Builds a SetReferrer instruction. Can only be set once per navigator.
+pub fn set_referrer(signer: Pubkey, referrer: Pubkey) -> Instruction {
+    let navigator_address = navigator_pda(signer).0;
+    let referrer_navigator_address = navigator_pda(referrer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(navigator_address, false),
+            AccountMeta::new(referrer_navigator_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetReferrer {}.to_bytes(),
+    }
+}
+
+/// Builds a ClaimReferral instruction to claim SOL referral rewards.
+pub fn claim_referral(signer: Pubkey) -> Instruction {
+    let navigator_address = navigator_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(navigator_address, false),
+        ],
+        data: ClaimReferral {}.to_bytes(),
+    }
+}
+
+/// Builds a CloseNavigator instruction to reclaim a navigator's rent. All miners must be
+/// closed and referral rewards claimed first.
+pub fn close_navigator(signer: Pubkey) -> Instruction {
+    let navigator_address = navigator_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(navigator_address, false),
+        ],
+        data: CloseNavigator {}.to_bytes(),
+    }
+}
+
+/// Builds a SetReferralFee instruction (admin only).
+pub fn set_referral_fee(signer: Pubkey, referral_fee_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetReferralFee {
+            referral_fee_bps: referral_fee_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SettleSeason instruction. `winners` are the leaderboard authorities in order;
+/// missing LUXITE token accounts are created, funded by the signer.
+pub fn settle_season(signer: Pubkey, season_id: u64, winners: &[Pubkey]) -> Instruction {
+    let grid_address = grid_pda().0;
+    let season_address = season_pda(season_id).0;
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(grid_address, false),
+        AccountMeta::new(season_address, false),
+        AccountMeta::new_readonly(MINT_ADDRESS, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ];
+    for winner in winners {
+        accounts.push(AccountMeta::new_readonly(*winner, false));
+        accounts.push(AccountMeta::new(
+            get_associated_token_address(winner, &MINT_ADDRESS),
+            false,
+        ));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: SettleSeason {}.to_bytes(),
+    }
+}
+
+/// Builds an OpenSeason instruction (admin only).
+pub fn open_season(signer: Pubkey, season_id: u64, metric: u64, prize_pool: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let grid_address = grid_pda().0;
+    let season_address = season_pda(season_id).0;
+    let treasury_address = treasury_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(grid_address, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OpenSeason {
+            season_id: season_id.to_le_bytes(),
+            metric: metric.to_le_bytes(),
+            prize_pool: prize_pool.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a Propose instruction to create a governance proposal for a config parameter.
+/// `proposal_id` is the current `Config.proposal_count`.
+pub fn propose(
+    signer: Pubkey,
+    stake_id: u64,
+    proposal_id: u64,
+    param: u64,
+    value: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let proposal_address = proposal_pda(proposal_id).0;
+    let stake_address = stake_pda(signer, stake_id).0;
+    let treasury_address = treasury_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(proposal_address, false),
+            AccountMeta::new_readonly(stake_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: Propose {
+            stake_id: stake_id.to_le_bytes(),
+            param: param.to_le_bytes(),
+            value: value.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a CastVote instruction to vote on a proposal with a stake position.
+pub fn cast_vote(signer: Pubkey, proposal_id: u64, stake_id: u64, support: bool) -> Instruction {
+    let proposal_address = proposal_pda(proposal_id).0;
+    let stake_address = stake_pda(signer, stake_id).0;
+    let vote_address = vote_pda(proposal_id, stake_address).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(proposal_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(vote_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CastVote {
+            proposal_id: proposal_id.to_le_bytes(),
+            stake_id: stake_id.to_le_bytes(),
+            support: (support as u64).to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds an ExecuteProposal instruction. Callable by anyone once the timelock has elapsed,
+/// until the proposal expires.
+pub fn execute_proposal(signer: Pubkey, proposal_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let proposal_address = proposal_pda(proposal_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(proposal_address, false),
+        ],
+        data: ExecuteProposal {
+            proposal_id: proposal_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a VetoProposal instruction (admin only, during the bootstrap period).
+pub fn veto_proposal(signer: Pubkey, proposal_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let proposal_address = proposal_pda(proposal_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(proposal_address, false),
+        ],
+        data: VetoProposal {
+            proposal_id: proposal_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a CreatePool instruction. The signer operates the pool and deploys for it.
+pub fn create_pool(signer: Pubkey, id: u64, dimension_id: u64) -> Instruction {
+    let pool_address = pool_pda(signer, id).0;
+    let navigator_address = navigator_pda(pool_address).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new(navigator_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CreatePool {
+            id: id.to_le_bytes(),
+            dimension_id: dimension_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SetPoolDimension instruction to move an idle pool to another dimension.
+pub fn set_pool_dimension(signer: Pubkey, id: u64, dimension_id: u64) -> Instruction {
+    let pool_address = pool_pda(signer, id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool_address, false),
+        ],
+        data: SetPoolDimension {
+            id: id.to_le_bytes(),
+            dimension_id: dimension_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a PoolDeposit instruction to deposit SOL into a pool for shares.
+pub fn pool_deposit(signer: Pubkey, pool: Pubkey, amount: u64) -> Instruction {
+    let member_address = pool_member_pda(pool, signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(member_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: PoolDeposit {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a PoolWithdraw instruction to redeem pool shares for SOL.
+pub fn pool_withdraw(signer: Pubkey, pool: Pubkey, shares: u64) -> Instruction {
+    let member_address = pool_member_pda(pool, signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(member_address, false),
+        ],
+        data: PoolWithdraw {
+            shares: shares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a PoolHarvest instruction to claim a pool miner's rewards into the pool. The miner
+/// must be checkpointed first.
+pub fn pool_harvest(signer: Pubkey, pool: Pubkey, dimension_id: u64) -> Instruction {
+    let miner_address = miner_pda(dimension_id, pool).0;
+    let navigator_address = navigator_pda(pool).0;
+    let pool_tokens_address = get_associated_token_address(&pool, &MINT_ADDRESS);
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(navigator_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: PoolHarvest {}.to_bytes(),
+    }
+}
+
+/// Builds a PoolClaim instruction to claim a member's SOL and LUXITE pool rewards.
+pub fn pool_claim(signer: Pubkey, pool: Pubkey) -> Instruction {
+    let member_address = pool_member_pda(pool, signer).0;
+    let recipient_tokens_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let pool_tokens_address = get_associated_token_address(&pool, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(member_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(recipient_tokens_address, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: PoolClaim {}.to_bytes(),
+    }
+}
+
+/// Builds a ViewMinerRewards instruction. Simulate it and decode the return data as a
+/// `MinerRewardsView`. `excavation_id` is the miner's current `excavation_id`.
+pub fn view_miner_rewards(
+    authority: Pubkey,
+    dimension_id: u64,
+    excavation_id: u64,
+    stake_id: Option<u64>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(config_pda().0, false),
+        AccountMeta::new_readonly(grid_pda().0, false),
+        AccountMeta::new_readonly(treasury_pda().0, false),
+        AccountMeta::new_readonly(miner_pda(dimension_id, authority).0, false),
+        AccountMeta::new_readonly(excavation_pda(dimension_id, excavation_id).0, false),
+    ];
+    if let Some(stake_id) = stake_id {
+        accounts.push(AccountMeta::new_readonly(stake_pda(authority, stake_id).0, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ViewMinerRewards {}.to_bytes(),
+    }
+}
+
+/// Builds a ViewStakeYield instruction. Simulate it and decode the return data as a
+/// `StakeYieldView`.
+pub fn view_stake_yield(authority: Pubkey, id: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(treasury_pda().0, false),
+            AccountMeta::new_readonly(stake_pda(authority, id).0, false),
+        ],
+        data: ViewStakeYield {}.to_bytes(),
+    }
+}
+
+/// Builds a ViewExcavation instruction. Simulate it and decode the return data as an
+/// `ExcavationView`.
+pub fn view_excavation(dimension_id: u64, tick_id: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(dimension_pda(dimension_id).0, false),
+            AccountMeta::new_readonly(drill_pda(dimension_id).0, false),
+            AccountMeta::new_readonly(excavation_pda(dimension_id, tick_id).0, false),
+        ],
+        data: ViewExcavation {}.to_bytes(),
+    }
+}
+
+/// Builds a SetMissRebate instruction (admin only). A non-zero `luxite_rate` (LUXITE per SOL)
+/// pays rebates in LUXITE instead of SOL.
+pub fn set_miss_rebate(signer: Pubkey, miss_rebate_bps: u64, luxite_rate: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetMissRebate {
+            miss_rebate_bps: miss_rebate_bps.to_le_bytes(),
+            luxite_rate: luxite_rate.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SetDeployCaps instruction (admin only). Caps of 0 are unlimited.
+pub fn set_deploy_caps(
+    signer: Pubkey,
+    max_miner_deploy: u64,
+    max_excavation_deploy: u64,
+    luxite_curve: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetDeployCaps {
+            max_miner_deploy: max_miner_deploy.to_le_bytes(),
+            max_excavation_deploy: max_excavation_deploy.to_le_bytes(),
+            luxite_curve: luxite_curve.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SetDeployWindow instruction (admin only).
+pub fn set_deploy_window(
+    signer: Pubkey,
+    deploy_cutoff_slots: u64,
+    late_deploy_decay_bps: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetDeployWindow {
+            deploy_cutoff_slots: deploy_cutoff_slots.to_le_bytes(),
+            late_deploy_decay_bps: late_deploy_decay_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SetUnbondingPeriod instruction (admin only).
+pub fn set_unbonding_period(signer: Pubkey, unbonding_period: i64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetUnbondingPeriod {
+            unbonding_period: unbonding_period.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds an InitializeVault instruction to create the staking vault (admin only).
+pub fn initialize_vault(signer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let vault_address = vault_pda().0;
+    let vault_mint_address = vault_mint_pda().0;
+    let stake_address = stake_pda(vault_address, VAULT_STAKE_ID).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = treasury_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(vault_address, false),
+            AccountMeta::new(vault_mint_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: InitializeVault {}.to_bytes(),
+    }
+}
+
+/// Builds a SetStakeSOLShare instruction (admin only).
+pub fn set_stake_sol_share(signer: Pubkey, stake_sol_share_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetStakeSOLShare {
+            stake_sol_share_bps: stake_sol_share_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a SetStakeBoost instruction (admin only).
+pub fn set_stake_boost(signer: Pubkey, max_boost_bps: u64, half_balance: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetStakeBoost {
+            max_boost_bps: max_boost_bps.to_le_bytes(),
+            half_balance: half_balance.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Builds a Migrate instruction to grow accounts created before their layout changed. Any
+/// signer may pay to migrate any account; accounts that are already current are skipped.
+/// `dimension_count` is the current `Config.dimension_count`, used to locate the index page
+/// that legacy dimensions are recorded on.
+pub fn migrate(signer: Pubkey, dimension_count: u64, accounts: &[Pubkey]) -> Instruction {
+    let config_address = config_pda().0;
+    let dimension_index_address = dimension_index_pda(DimensionIndex::page_of(dimension_count)).0;
+    let mut metas = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(dimension_index_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    metas.extend(accounts.iter().map(|account| AccountMeta::new(*account, false)));
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: metas,
+        data: Migrate {}.to_bytes(),
+    }
+}
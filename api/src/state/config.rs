@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
+use crate::consts::*;
 use crate::state::config_pda;
 use super::LocalUniverseAccount;
 
@@ -15,23 +16,223 @@ pub struct Config {
     /// The fee for scanning a new dimension (in lamports).
     pub scan_fee: u64,
 
-    /// Buffer a (placeholder)
-    pub buffer_a: u64,
+    /// The next dimension ID to be assigned by an auto-assign scan.
+    pub next_dimension_id: u64,
 
-    /// Buffer b (placeholder)
-    pub buffer_b: u64,
+    /// The total number of dimensions recorded in the dimension index.
+    pub dimension_count: u64,
 
-    /// Buffer c (placeholder)
-    pub buffer_c: u64,
+    /// The share of deploy and scan fees paid to referrers (in basis points).
+    pub referral_fee_bps: u64,
 
-    /// Buffer d (placeholder)
-    pub buffer_d: u64,
+    /// The share of a missed excavation's SOL rebated to its miners (in basis points).
+    pub miss_rebate_bps: u64,
+
+    /// LUXITE paid per SOL of miss rebate (0 = rebate in SOL).
+    pub miss_rebate_luxite_rate: u64,
+
+    /// The maximum SOL a miner can deploy per excavation (0 = unlimited).
+    pub max_miner_deploy: u64,
+
+    /// The maximum SOL deployed per excavation (0 = unlimited).
+    pub max_excavation_deploy: u64,
+
+    /// The curve weighting each miner's LUXITE share by deployed SOL (LUXITE_CURVE_*).
+    pub luxite_curve: u64,
+
+    /// Slots before the end of a tick in which deploys are rejected.
+    pub deploy_cutoff_slots: u64,
+
+    /// Weight lost by a deploy at the very end of a tick, decaying linearly from the start
+    /// (in basis points).
+    pub late_deploy_decay_bps: u64,
+
+    /// Seconds a stake withdrawal spends unbonding before it can be completed.
+    pub unbonding_period: i64,
+
+    /// The share of SOL revenue streamed to stakers instead of buybacks (in basis points).
+    pub stake_sol_share_bps: u64,
+
+    /// The maximum LUXITE boost on mining rewards from staking (in basis points).
+    pub stake_boost_max_bps: u64,
+
+    /// The staked balance earning half of the maximum mining boost.
+    pub stake_boost_half_balance: u64,
+
+    /// The number of governance proposals created (and the next proposal ID).
+    pub proposal_count: u64,
+
+    /// The timestamp until which the admin can veto governance proposals.
+    pub admin_veto_until: i64,
 }
 
 impl Config {
     pub fn pda() -> (Pubkey, u8) {
         config_pda()
     }
+
+    /// Returns the time-weighted value of an amount deployed at a slot within a tick.
+    pub fn time_weighted(&self, amount: u64, slot: u64, start_slot: u64, end_slot: u64) -> u64 {
+        let duration = end_slot.saturating_sub(start_slot);
+        if self.late_deploy_decay_bps == 0 || duration == 0 {
+            return amount;
+        }
+        let elapsed = slot.saturating_sub(start_slot).min(duration);
+        let decay = self.late_deploy_decay_bps.min(DENOMINATOR_BPS) * elapsed / duration;
+        ((amount as u128 * (DENOMINATOR_BPS - decay) as u128) / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// Sets a governable parameter. Returns false if the parameter is unknown or the value is
+    /// outside the bounds enforced by the admin setters.
+    pub fn set_param(&mut self, param: u64, value: u64) -> bool {
+        match param {
+            PARAM_SCAN_FEE if value <= MAX_SCAN_FEE => self.scan_fee = value,
+            PARAM_REFERRAL_FEE_BPS if value <= DENOMINATOR_BPS => self.referral_fee_bps = value,
+            PARAM_MISS_REBATE_BPS if value <= MAX_MISS_REBATE_BPS => self.miss_rebate_bps = value,
+            PARAM_MISS_REBATE_LUXITE_RATE if value <= MAX_MISS_REBATE_LUXITE_RATE => {
+                self.miss_rebate_luxite_rate = value
+            }
+            PARAM_MAX_MINER_DEPLOY if Self::is_valid_deploy_cap(value) => {
+                self.max_miner_deploy = value
+            }
+            PARAM_MAX_EXCAVATION_DEPLOY if Self::is_valid_deploy_cap(value) => {
+                self.max_excavation_deploy = value
+            }
+            PARAM_LUXITE_CURVE if value == LUXITE_CURVE_LINEAR || value == LUXITE_CURVE_SQRT => {
+                self.luxite_curve = value
+            }
+            PARAM_DEPLOY_CUTOFF_SLOTS if value < TICK_DURATION_SLOTS => {
+                self.deploy_cutoff_slots = value
+            }
+            PARAM_LATE_DEPLOY_DECAY_BPS if value <= DENOMINATOR_BPS => {
+                self.late_deploy_decay_bps = value
+            }
+            PARAM_UNBONDING_PERIOD
+                if (MIN_UNBONDING_PERIOD as u64..=MAX_UNBONDING_PERIOD as u64).contains(&value) =>
+            {
+                self.unbonding_period = value as i64
+            }
+            PARAM_STAKE_SOL_SHARE_BPS if value <= DENOMINATOR_BPS => {
+                self.stake_sol_share_bps = value
+            }
+            PARAM_STAKE_BOOST_MAX_BPS if value <= MAX_STAKE_BOOST_BPS => {
+                self.stake_boost_max_bps = value
+            }
+            PARAM_STAKE_BOOST_HALF_BALANCE => self.stake_boost_half_balance = value,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns true if a deploy cap is unlimited (0) or large enough not to block deploys.
+    pub fn is_valid_deploy_cap(cap: u64) -> bool {
+        cap == 0 || cap >= MIN_DEPLOY_CAP
+    }
+
+    /// Returns true if the admin can still veto governance proposals.
+    pub fn can_veto(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.admin_veto_until
+    }
+
+    /// Returns the extra LUXITE a staked balance earns on a mining reward. The boost saturates
+    /// towards the maximum, reaching half of it at the half balance.
+    pub fn stake_boost(&self, rewards: u64, staked: u64) -> u64 {
+        if self.stake_boost_max_bps == 0 || staked == 0 {
+            return 0;
+        }
+        let boost_bps = (self.stake_boost_max_bps as u128 * staked as u128)
+            / (staked as u128 + self.stake_boost_half_balance as u128);
+        ((rewards as u128 * boost_bps) / DENOMINATOR_BPS as u128) as u64
+    }
 }
 
 account!(LocalUniverseAccount, Config);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_param_bounds_miss_rebate_luxite_rate() {
+        let mut config = Config::zeroed();
+        assert!(config.set_param(PARAM_MISS_REBATE_LUXITE_RATE, MAX_MISS_REBATE_LUXITE_RATE));
+        assert_eq!(config.miss_rebate_luxite_rate, MAX_MISS_REBATE_LUXITE_RATE);
+        assert!(!config.set_param(PARAM_MISS_REBATE_LUXITE_RATE, MAX_MISS_REBATE_LUXITE_RATE + 1));
+        assert_eq!(config.miss_rebate_luxite_rate, MAX_MISS_REBATE_LUXITE_RATE);
+    }
+
+    #[test]
+    fn set_param_bounds_max_miner_deploy() {
+        let mut config = Config::zeroed();
+        assert!(config.set_param(PARAM_MAX_MINER_DEPLOY, MIN_DEPLOY_CAP));
+        assert!(!config.set_param(PARAM_MAX_MINER_DEPLOY, MIN_DEPLOY_CAP - 1));
+        assert_eq!(config.max_miner_deploy, MIN_DEPLOY_CAP);
+        assert!(config.set_param(PARAM_MAX_MINER_DEPLOY, 0));
+        assert_eq!(config.max_miner_deploy, 0);
+    }
+
+    #[test]
+    fn set_param_bounds_max_excavation_deploy() {
+        let mut config = Config::zeroed();
+        assert!(config.set_param(PARAM_MAX_EXCAVATION_DEPLOY, MIN_DEPLOY_CAP));
+        assert!(!config.set_param(PARAM_MAX_EXCAVATION_DEPLOY, 1));
+        assert_eq!(config.max_excavation_deploy, MIN_DEPLOY_CAP);
+        assert!(config.set_param(PARAM_MAX_EXCAVATION_DEPLOY, 0));
+        assert_eq!(config.max_excavation_deploy, 0);
+    }
+
+    #[test]
+    fn time_weighted_decays_linearly_over_the_tick() {
+        let mut config = Config::zeroed();
+        config.late_deploy_decay_bps = 5_000;
+
+        assert_eq!(config.time_weighted(1_000, 100, 100, 200), 1_000);
+        assert_eq!(config.time_weighted(1_000, 150, 100, 200), 750);
+        assert_eq!(config.time_weighted(1_000, 200, 100, 200), 500);
+
+        // Slots past the end decay no further
+        assert_eq!(config.time_weighted(1_000, 300, 100, 200), 500);
+    }
+
+    #[test]
+    fn time_weighted_is_flat_without_decay_or_duration() {
+        let mut config = Config::zeroed();
+        assert_eq!(config.time_weighted(1_000, 150, 100, 200), 1_000);
+
+        config.late_deploy_decay_bps = 5_000;
+        assert_eq!(config.time_weighted(1_000, 150, 100, 100), 1_000);
+    }
+
+    #[test]
+    fn stake_boost_is_zero_when_disabled_or_unstaked() {
+        let mut config = Config::zeroed();
+        config.stake_boost_half_balance = 1_000;
+        assert_eq!(config.stake_boost(10_000, 1_000), 0);
+
+        config.stake_boost_max_bps = 5_000;
+        assert_eq!(config.stake_boost(10_000, 0), 0);
+    }
+
+    #[test]
+    fn stake_boost_reaches_half_at_half_balance() {
+        let mut config = Config::zeroed();
+        config.stake_boost_max_bps = 5_000;
+        config.stake_boost_half_balance = 1_000;
+
+        // Half of the 50% maximum on a 10,000 reward
+        assert_eq!(config.stake_boost(10_000, 1_000), 2_500);
+    }
+
+    #[test]
+    fn stake_boost_saturates_below_maximum() {
+        let mut config = Config::zeroed();
+        config.stake_boost_max_bps = 5_000;
+        config.stake_boost_half_balance = 1_000;
+
+        let small = config.stake_boost(10_000, 100);
+        let large = config.stake_boost(10_000, 1_000_000);
+        assert!(small < large);
+        assert!(large < 5_000);
+        assert_eq!(config.stake_boost(10_000, u64::MAX), 4_999);
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
+use crate::consts::*;
 use crate::state::dimension_pda;
 use super::LocalUniverseAccount;
 
@@ -24,23 +25,77 @@ pub struct Dimension {
     /// Explicit padding for alignment.
     pub _padding: u32,
 
-    /// Buffer a (placeholder).
-    pub buffer_a: u64,
+    /// Drill depth from which richness depletion is measured.
+    pub depth_offset: u64,
 
-    /// Buffer b (placeholder).
-    pub buffer_b: u64,
+    /// Unix timestamp when last refined.
+    pub refined_at: i64,
 
-    /// Buffer c (placeholder).
-    pub buffer_c: u64,
+    /// The slot whose hash decides a pending re-roll (0 = none pending).
+    pub reroll_slot: u64,
 
-    /// Buffer d (placeholder).
-    pub buffer_d: u64,
+    /// Whether this dimension is recorded in the dimension index (1) or not yet (0).
+    pub indexed: u64,
 }
 
 impl Dimension {
     pub fn pda(&self) -> (Pubkey, u8) {
         dimension_pda(self.id)
     }
+
+    /// Returns the effective richness at the given drill depth. Depletion raises richness
+    /// towards MAX_RICHNESS, losing half of the remaining headroom every
+    /// RICHNESS_HALF_DEPLETION_DEPTH excavations.
+    pub fn richness_at(&self, depth: u64) -> u64 {
+        let richness = (self.richness as u64).min(MAX_RICHNESS);
+        let depleted = depth.saturating_sub(self.depth_offset) as u128;
+        let headroom = (MAX_RICHNESS - richness) as u128;
+        let increase = headroom * depleted / (depleted + RICHNESS_HALF_DEPLETION_DEPTH as u128);
+        richness + increase as u64
+    }
 }
 
 account!(LocalUniverseAccount, Dimension);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimension(richness: u32, depth_offset: u64) -> Dimension {
+        let mut dimension = Dimension::zeroed();
+        dimension.richness = richness;
+        dimension.depth_offset = depth_offset;
+        dimension
+    }
+
+    #[test]
+    fn richness_at_starts_at_scanned_richness() {
+        let dimension = dimension(500_000_000, 100);
+        assert_eq!(dimension.richness_at(100), 500_000_000);
+
+        // Depths before the offset are not depleted
+        assert_eq!(dimension.richness_at(0), 500_000_000);
+    }
+
+    #[test]
+    fn richness_at_halves_headroom_at_half_depletion_depth() {
+        let dimension = dimension(500_000_000, 100);
+        let headroom = MAX_RICHNESS - 500_000_000;
+        assert_eq!(
+            dimension.richness_at(100 + RICHNESS_HALF_DEPLETION_DEPTH),
+            500_000_000 + headroom / 2
+        );
+    }
+
+    #[test]
+    fn richness_at_never_exceeds_maximum() {
+        let poor = dimension(1, 0);
+        let richness = poor.richness_at(u64::MAX);
+        assert!(richness < MAX_RICHNESS);
+        assert!(richness > poor.richness_at(RICHNESS_HALF_DEPLETION_DEPTH));
+
+        // Scanned richness above the maximum is clamped
+        let rich = dimension(u32::MAX, 0);
+        assert_eq!(rich.richness_at(RICHNESS_HALF_DEPLETION_DEPTH), MAX_RICHNESS);
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::DIMENSION_INDEX_PAGE_SIZE;
+use crate::state::dimension_index_pda;
+use super::LocalUniverseAccount;
+
+/// A page of the on-chain dimension registry. Lets clients enumerate dimensions in scan order
+/// without a full program account scan.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct DimensionIndex {
+    /// The page number.
+    pub page: u64,
+
+    /// The number of dimension IDs recorded on this page.
+    pub count: u64,
+
+    /// The dimension IDs recorded on this page, in scan order.
+    pub ids: [u64; DIMENSION_INDEX_PAGE_SIZE],
+}
+
+impl DimensionIndex {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        dimension_index_pda(self.page)
+    }
+
+    /// Returns the page holding the dimension with the given scan position.
+    pub fn page_of(position: u64) -> u64 {
+        position / DIMENSION_INDEX_PAGE_SIZE as u64
+    }
+
+    /// Returns the recorded dimension IDs.
+    pub fn ids(&self) -> &[u64] {
+        &self.ids[..(self.count as usize).min(DIMENSION_INDEX_PAGE_SIZE)]
+    }
+
+    /// Appends a dimension ID to this page.
+    pub fn push(&mut self, dimension_id: u64) {
+        assert!((self.count as usize) < DIMENSION_INDEX_PAGE_SIZE, "Dimension index page full");
+        self.ids[self.count as usize] = dimension_id;
+        self.count += 1;
+    }
+}
+
+account!(LocalUniverseAccount, DimensionIndex);
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::DENOMINATOR_BPS;
 use crate::state::drill_pda;
 use super::LocalUniverseAccount;
 
-/// Global drill state for a dimension. Tracks lifetime mining stats only.
+/// Global drill state for a dimension. Tracks lifetime mining stats and hit history.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
 pub struct Drill {
@@ -20,6 +21,24 @@ pub struct Drill {
     /// Lifetime LUXITE earned across all excavations.
     pub lifetime_rewards_luxite: u64,
 
+    /// Total number of excavations that hit.
+    pub hits: u64,
+
+    /// Total number of excavations that missed.
+    pub misses: u64,
+
+    /// Current streak of identical outcomes (positive = hits, negative = misses).
+    pub streak: i64,
+
+    /// Longest run of consecutive hits.
+    pub longest_hit_streak: u64,
+
+    /// Longest run of consecutive misses.
+    pub longest_miss_streak: u64,
+
+    /// Outcomes of the last 256 excavations (bit 0 = most recent, 1 = hit).
+    pub history: [u64; 4],
+
     /// Reserved for future use.
     pub buffer_a: u64,
 
@@ -37,6 +56,99 @@ impl Drill {
     pub fn pda(&self) -> (Pubkey, u8) {
         drill_pda(self.dimension_id)
     }
+
+    /// Records the outcome of a processed excavation.
+    pub fn record(&mut self, did_hit: bool) {
+        if did_hit {
+            self.hits += 1;
+            self.streak = if self.streak > 0 { self.streak + 1 } else { 1 };
+            self.longest_hit_streak = self.longest_hit_streak.max(self.streak as u64);
+        } else {
+            self.misses += 1;
+            self.streak = if self.streak < 0 { self.streak - 1 } else { -1 };
+            self.longest_miss_streak = self.longest_miss_streak.max(self.streak.unsigned_abs());
+        }
+
+        // Shift history left by one, carrying the top bit of each word into the next
+        for i in (1..self.history.len()).rev() {
+            self.history[i] = (self.history[i] << 1) | (self.history[i - 1] >> 63);
+        }
+        self.history[0] = (self.history[0] << 1) | did_hit as u64;
+    }
+
+    /// Returns the number of outcomes held in the history bitmap.
+    pub fn history_len(&self) -> u64 {
+        (self.hits + self.misses).min(256)
+    }
+
+    /// Returns the number of hits in the history bitmap.
+    pub fn recent_hits(&self) -> u64 {
+        self.history.iter().map(|w| w.count_ones() as u64).sum()
+    }
+
+    /// Returns the lifetime hit rate in basis points.
+    pub fn hit_rate_bps(&self) -> u64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0;
+        }
+        self.hits * DENOMINATOR_BPS / total
+    }
+
+    /// Returns the hit rate over the history bitmap in basis points.
+    pub fn recent_hit_rate_bps(&self) -> u64 {
+        let len = self.history_len();
+        if len == 0 {
+            return 0;
+        }
+        self.recent_hits() * DENOMINATOR_BPS / len
+    }
 }
 
 account!(LocalUniverseAccount, Drill);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_tracks_streaks() {
+        let mut drill = Drill::zeroed();
+        drill.record(true);
+        drill.record(true);
+        drill.record(false);
+
+        assert_eq!((drill.hits, drill.misses), (2, 1));
+        assert_eq!(drill.streak, -1);
+        assert_eq!(drill.longest_hit_streak, 2);
+        assert_eq!(drill.longest_miss_streak, 1);
+        assert_eq!(drill.history[0], 0b110);
+    }
+
+    #[test]
+    fn record_carries_history_across_words() {
+        let mut drill = Drill::zeroed();
+        for _ in 0..64 {
+            drill.record(true);
+        }
+        drill.record(false);
+
+        assert_eq!(drill.history[0], u64::MAX << 1);
+        assert_eq!(drill.history[1], 1);
+        assert_eq!(drill.history_len(), 65);
+        assert_eq!(drill.recent_hits(), 64);
+    }
+
+    #[test]
+    fn record_drops_outcomes_past_history() {
+        let mut drill = Drill::zeroed();
+        drill.record(true);
+        for _ in 0..256 {
+            drill.record(false);
+        }
+
+        assert_eq!(drill.recent_hits(), 0);
+        assert_eq!(drill.history_len(), 256);
+        assert_eq!(drill.longest_miss_streak, 256);
+    }
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::excavation_pda;
+use crate::consts::{
+    DENOMINATOR_BPS, LUXITE_CURVE_SQRT, MAX_RICHNESS, MIN_DEPLOYED_FOR_FULL_RATE,
+};
+use crate::state::{excavation_pda, Stake};
 use super::LocalUniverseAccount;
 
 /// Per-tick mining state for a dimension. Closeable after expiry to reclaim rent.
@@ -35,17 +38,26 @@ pub struct Excavation {
     /// The amount of LUXITE distributed this excavation.
     pub luxite_distributed: u64,
 
-    /// Reserved for future use.
-    pub buffer_a: u64,
+    /// The miss rebate in basis points, snapshotted from config when processed.
+    pub miss_rebate_bps: u64,
 
-    /// Reserved for future use.
-    pub buffer_b: u64,
+    /// LUXITE paid per SOL of miss rebate, snapshotted from config (0 = rebate in SOL).
+    pub miss_rebate_luxite_rate: u64,
 
-    /// Reserved for future use.
-    pub buffer_c: u64,
+    /// The LUXITE reserved from the treasury for miss rebates.
+    pub rebate_luxite: u64,
 
-    /// Reserved for future use.
-    pub buffer_d: u64,
+    /// The sum of miner LUXITE weights in this excavation.
+    pub total_weight: u64,
+
+    /// The curve weighting miner LUXITE shares, snapshotted from config at creation.
+    pub luxite_curve: u64,
+
+    /// The SOL deployed by conditional miners in this excavation (after fees).
+    pub conditional_deployed: u64,
+
+    /// The timestamp of the first deploy, which opened this excavation (0 = unknown).
+    pub opened_at: i64,
 }
 
 impl Excavation {
@@ -53,6 +65,12 @@ impl Excavation {
         excavation_pda(self.dimension_id, self.id)
     }
 
+    /// Returns true if a stake position's balance was held since before this excavation
+    /// opened, so it may boost the excavation's rewards.
+    pub fn is_staked_before(&self, stake: &Stake) -> bool {
+        self.opened_at > 0 && stake.last_deposit_at < self.opened_at
+    }
+
     /// Generates RNG from slot hash. Returns None if hash is invalid.
     pub fn rng(&self) -> Option<u64> {
         if self.slot_hash == [0; 32] || self.slot_hash == [u8::MAX; 32] {
@@ -65,15 +83,142 @@ impl Excavation {
         Some(r1 ^ r2 ^ r3 ^ r4)
     }
 
+    /// Returns the roll compared against richness, or None if the excavation has no valid RNG.
+    pub fn roll(&self) -> Option<u64> {
+        self.rng().map(|rng| rng % 1_000_000_000)
+    }
+
+    /// Returns the richness this excavation rolls against. Excavations below
+    /// MIN_DEPLOYED_FOR_FULL_RATE are penalized by scaling richness up.
+    pub fn effective_richness(&self, richness: u64) -> u64 {
+        if self.total_deployed >= MIN_DEPLOYED_FOR_FULL_RATE {
+            return richness;
+        }
+        let scale = MIN_DEPLOYED_FOR_FULL_RATE
+            .checked_div(self.total_deployed)
+            .unwrap_or(10);
+        richness.saturating_mul(scale).min(MAX_RICHNESS)
+    }
+
+    /// Returns the probability of a hit in basis points for a richness. A roll hits when it
+    /// is above the effective richness.
+    pub fn hit_probability_bps(&self, richness: u64) -> u64 {
+        let hits = MAX_RICHNESS - self.effective_richness(richness).min(MAX_RICHNESS);
+        ((hits as u128 * DENOMINATOR_BPS as u128) / 1_000_000_000) as u64
+    }
+
     /// Returns true if this excavation was a hit.
     pub fn hit(&self) -> bool {
         self.did_hit == 1
     }
 
+    /// Returns a miner's LUXITE weight for an amount deployed under this excavation's curve.
+    pub fn weight(&self, deployed: u64) -> u64 {
+        match self.luxite_curve {
+            LUXITE_CURVE_SQRT => isqrt(deployed),
+            _ => deployed,
+        }
+    }
+
+    /// Returns a miner's share of an amount of LUXITE. Falls back to a linear split by
+    /// deployed SOL for excavations without weights.
+    pub fn luxite_share(&self, amount: u64, weight: u64, deployed: u64) -> u64 {
+        let (numerator, denominator) = if self.total_weight > 0 {
+            (weight, self.total_weight)
+        } else {
+            (deployed, self.total_deployed)
+        };
+        if denominator == 0 {
+            return 0;
+        }
+        ((amount as u128 * numerator as u128) / denominator as u128) as u64
+    }
+
+    /// Returns true if miss rebates are paid in LUXITE.
+    pub fn rebates_in_luxite(&self) -> bool {
+        self.miss_rebate_luxite_rate > 0
+    }
+
+    /// Returns the SOL miss rebate owed on an amount deployed.
+    pub fn miss_rebate(&self, deployed: u64) -> u64 {
+        ((deployed as u128 * self.miss_rebate_bps as u128) / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// Returns a miner's share of the LUXITE reserved for miss rebates.
+    pub fn rebate_luxite_share(&self, deployed: u64) -> u64 {
+        if self.total_deployed == 0 {
+            return 0;
+        }
+        ((self.rebate_luxite as u128 * deployed as u128) / self.total_deployed as u128) as u64
+    }
+
     /// Returns true if this excavation has been processed.
     pub fn is_processed(&self) -> bool {
         self.slot_hash != [0; 32]
     }
+
+    /// Returns true if this excavation can be closed: expired, or stale (unprocessed and old).
+    pub fn is_closeable(&self, clock: &Clock, tick_id: u64) -> bool {
+        let is_expired = clock.slot >= self.expires_at;
+        let is_stale = !self.is_processed() && self.id + 1 < tick_id;
+        self.id < tick_id && (is_expired || is_stale)
+    }
+}
+
+/// Integer square root (floor), by Newton's method.
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x / 2 + 1;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
 }
 
 account!(LocalUniverseAccount, Excavation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_rounds_down() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(3), 1);
+        assert_eq!(isqrt(4), 2);
+        assert_eq!(isqrt(99), 9);
+        assert_eq!(isqrt(100), 10);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        for n in 0..10_000u64 {
+            let root = isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n);
+        }
+    }
+
+    #[test]
+    fn luxite_share_splits_by_weight() {
+        let mut excavation = Excavation::zeroed();
+        excavation.luxite_curve = LUXITE_CURVE_SQRT;
+        excavation.total_deployed = 10_100;
+        excavation.total_weight = excavation.weight(100) + excavation.weight(10_000);
+
+        // 100 and 10,000 deployed weigh 10 and 100 under the sqrt curve
+        assert_eq!(excavation.luxite_share(1_100, excavation.weight(100), 100), 100);
+        assert_eq!(excavation.luxite_share(1_100, excavation.weight(10_000), 10_000), 1_000);
+    }
+
+    #[test]
+    fn luxite_share_falls_back_to_deployed() {
+        let mut excavation = Excavation::zeroed();
+        excavation.total_deployed = 10;
+        assert_eq!(excavation.luxite_share(1_000, 0, 3), 300);
+
+        excavation.total_deployed = 0;
+        assert_eq!(excavation.luxite_share(1_000, 0, 0), 0);
+    }
+}
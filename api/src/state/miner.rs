@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::{miner_pda, Treasury};
+use crate::state::{miner_pda, Config, Excavation, Treasury};
 use super::LocalUniverseAccount;
 
 /// Tracks a miner's deployed SOL and reward balances for a dimension.
@@ -53,24 +53,126 @@ pub struct Miner {
     /// The total amount of SOL this miner has deployed across all excavations.
     pub lifetime_deployed: u64,
 
-    /// Reserved for future use.
-    pub buffer_a: u64,
+    /// This miner's LUXITE weight in the current excavation.
+    pub weight: u64,
 
-    /// Reserved for future use.
-    pub buffer_b: u64,
+    /// Time-weighted SOL this miner has deployed this excavation (after fee).
+    pub weighted_deployed: u64,
 
-    /// Reserved for future use.
-    pub buffer_c: u64,
+    /// The excavation total below which this miner's deploy is refunded (0 = unconditional).
+    pub min_total: u64,
+
+    /// A key allowed to claim rewards on behalf of the authority (default = none).
+    pub claim_delegate: Pubkey,
+
+    /// The wallet that receives claimed rewards (default = the authority).
+    pub recipient: Pubkey,
 
     /// Reserved for future use.
     pub buffer_d: u64,
 }
 
+/// The rewards a checkpoint credits to a miner for a processed excavation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CheckpointRewards {
+    /// The SOL credited to the miner.
+    pub sol: u64,
+
+    /// The LUXITE credited to the miner, including the stake boost.
+    pub luxite: u64,
+
+    /// The stake boost paid from emissions.
+    pub boost: u64,
+
+    /// The conditional SOL held on the excavation that goes to the treasury.
+    pub treasury_sol: u64,
+
+    /// The LUXITE share forfeited by a refunded deploy, returned to emissions.
+    pub forfeited_luxite: u64,
+
+    /// Whether the deploy was refunded for falling below its threshold.
+    pub refunded: bool,
+}
+
 impl Miner {
     pub fn pda(&self) -> (Pubkey, u8) {
         miner_pda(self.dimension_id, self.authority)
     }
 
+    /// Returns true if a key may claim this miner's rewards.
+    pub fn can_claim(&self, key: &Pubkey) -> bool {
+        *key == self.authority
+            || (self.claim_delegate != Pubkey::default() && *key == self.claim_delegate)
+    }
+
+    /// Returns the wallet that receives this miner's claimed rewards.
+    pub fn claim_recipient(&self) -> Pubkey {
+        if self.recipient == Pubkey::default() {
+            self.authority
+        } else {
+            self.recipient
+        }
+    }
+
+    /// Returns true if this miner's deploy is refunded for an excavation total.
+    pub fn is_refunded(&self, total_deployed: u64) -> bool {
+        self.min_total > 0 && total_deployed < self.min_total
+    }
+
+    /// Returns the rewards a checkpoint of a processed excavation credits to this miner. The
+    /// stake boost is capped by the LUXITE left for emissions.
+    pub fn checkpoint_rewards(
+        &self,
+        excavation: &Excavation,
+        config: &Config,
+        luxite_balance: u64,
+        staked: u64,
+    ) -> CheckpointRewards {
+        let deployed = self.deployed;
+        let mut rewards = CheckpointRewards::default();
+
+        // Conditional deploys below their threshold are refunded in full
+        rewards.refunded = deployed > 0 && self.is_refunded(excavation.total_deployed);
+
+        if rewards.refunded {
+            rewards.sol = deployed;
+
+            // Return the forfeited LUXITE share to emissions
+            rewards.forfeited_luxite = if excavation.hit() {
+                excavation.luxite_share(excavation.luxite_distributed, self.weight, deployed)
+            } else {
+                excavation.rebate_luxite_share(deployed)
+            };
+        } else if excavation.hit() && deployed > 0 {
+            rewards.sol = deployed;
+
+            if excavation.luxite_distributed > 0 {
+                rewards.luxite =
+                    excavation.luxite_share(excavation.luxite_distributed, self.weight, deployed);
+            }
+
+            // Boost the share by the authority's stake, paid from emissions
+            rewards.boost = config
+                .stake_boost(rewards.luxite, staked)
+                .min(luxite_balance);
+            rewards.luxite += rewards.boost;
+        } else if deployed > 0 {
+            // Miss rebate
+            if excavation.rebates_in_luxite() {
+                rewards.luxite = excavation.rebate_luxite_share(deployed);
+            } else {
+                rewards.sol = excavation.miss_rebate(deployed);
+            }
+
+            // Conditional SOL was held on the excavation, send the rest to treasury
+            if self.min_total > 0 {
+                rewards.treasury_sol = deployed - rewards.sol;
+            }
+        }
+
+        rewards
+    }
+
     /// Claims pending LUXITE rewards. Charges 10% fee redistributed to other miners.
     pub fn claim_luxite(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
         self.update_rewards(treasury);
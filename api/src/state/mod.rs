@@ -1,24 +1,42 @@
 mod automation;
 mod config;
 mod dimension;
+mod dimension_index;
 mod drill;
 mod excavation;
 mod grid;
 mod miner;
 mod navigator;
+mod pool;
+mod pool_member;
+mod proposal;
+mod season;
+mod session_key;
 mod stake;
 mod treasury;
+mod vault;
+mod vault_redemption;
+mod vote;
 
 pub use automation::*;
 pub use config::*;
 pub use dimension::*;
+pub use dimension_index::*;
 pub use drill::*;
 pub use excavation::*;
 pub use grid::*;
 pub use miner::*;
 pub use navigator::*;
+pub use pool::*;
+pub use pool_member::*;
+pub use proposal::*;
+pub use season::*;
+pub use session_key::*;
 pub use stake::*;
 pub use treasury::*;
+pub use vault::*;
+pub use vault_redemption::*;
+pub use vote::*;
 
 use steel::*;
 
@@ -37,6 +55,15 @@ pub enum LocalUniverseAccount {
     Navigator = 106,
     Stake = 107,
     Treasury = 108,
+    DimensionIndex = 109,
+    Season = 110,
+    Vault = 111,
+    Proposal = 112,
+    Vote = 113,
+    SessionKey = 114,
+    Pool = 115,
+    PoolMember = 116,
+    VaultRedemption = 117,
 }
 
 /// PDA for automation (per authority per dimension).
@@ -57,6 +84,11 @@ pub fn dimension_pda(id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[DIMENSION, &id.to_le_bytes()], &crate::ID)
 }
 
+/// PDA for a page of the dimension index.
+pub fn dimension_index_pda(page: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DIMENSION_INDEX, &page.to_le_bytes()], &crate::ID)
+}
+
 /// PDA for a drill (global per dimension).
 pub fn drill_pda(dimension_id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[DRILL, &dimension_id.to_le_bytes()], &crate::ID)
@@ -88,12 +120,67 @@ pub fn navigator_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[NAVIGATOR, authority.as_ref()], &crate::ID)
 }
 
-/// PDA for a stake account.
-pub fn stake_pda(authority: Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[STAKE, authority.as_ref()], &crate::ID)
+/// PDA for a mining pool (per operator and pool ID).
+pub fn pool_pda(authority: Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL, authority.as_ref(), &id.to_le_bytes()], &crate::ID)
+}
+
+/// PDA for a pool member (per pool per authority).
+pub fn pool_member_pda(pool: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_MEMBER, pool.as_ref(), authority.as_ref()], &crate::ID)
+}
+
+/// PDA for a governance proposal.
+pub fn proposal_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROPOSAL, &id.to_le_bytes()], &crate::ID)
+}
+
+/// PDA for a season (per epoch).
+pub fn season_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEASON, &id.to_le_bytes()], &crate::ID)
+}
+
+/// PDA for a session key (per authority per key).
+pub fn session_key_pda(authority: Pubkey, key: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SESSION, authority.as_ref(), key.as_ref()], &crate::ID)
+}
+
+/// PDA for a stake position (per authority and position ID).
+pub fn stake_pda(authority: Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&stake_seeds(authority.as_ref(), &id.to_le_bytes()), &crate::ID)
+}
+
+/// Seeds for a stake position. Position 0 keeps the original per-authority address, so
+/// stakes opened before positions existed remain valid.
+pub fn stake_seeds<'a>(authority: &'a [u8], id: &'a [u8; 8]) -> Vec<&'a [u8]> {
+    if *id == [0; 8] {
+        vec![STAKE, authority]
+    } else {
+        vec![STAKE, authority, id]
+    }
 }
 
 /// PDA for the treasury.
 pub fn treasury_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TREASURY], &crate::ID)
 }
+
+/// PDA for a vote (per proposal per stake position).
+pub fn vote_pda(proposal_id: u64, stake: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE, &proposal_id.to_le_bytes(), stake.as_ref()], &crate::ID)
+}
+
+/// PDA for the staking vault.
+pub fn vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT], &crate::ID)
+}
+
+/// PDA for the staking vault's receipt mint.
+pub fn vault_mint_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_MINT], &crate::ID)
+}
+
+/// PDA for a pending vault redemption (per authority).
+pub fn vault_redemption_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_REDEMPTION, authority.as_ref()], &crate::ID)
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
+use crate::consts::*;
 use crate::state::navigator_pda;
 use super::LocalUniverseAccount;
 
@@ -24,26 +25,210 @@ pub struct Navigator {
     /// Unix timestamp when this navigator was created.
     pub created_at: i64,
 
-    /// Buffer a (placeholder).
-    pub buffer_a: u64,
+    /// The authority that referred this navigator (default if none).
+    pub referrer: Pubkey,
 
-    /// Buffer b (placeholder).
-    pub buffer_b: u64,
+    /// The amount of SOL earned from referrals that can be claimed.
+    pub referral_rewards_sol: u64,
 
-    /// Buffer c (placeholder).
-    pub buffer_c: u64,
+    /// Lifetime SOL earned from referrals.
+    pub lifetime_referral_rewards_sol: u64,
 
-    /// Buffer d (placeholder).
-    pub buffer_d: u64,
+    /// Total navigators referred by this navigator.
+    pub referral_count: u64,
 
-    /// Buffer e (placeholder).
-    pub buffer_e: u64,
+    /// Experience points computed from lifetime stats.
+    pub xp: u64,
+
+    /// Current rank (index into RANK_XP_THRESHOLDS).
+    pub rank: u64,
+
+    /// Bitmask of earned badges.
+    pub badges: u64,
+
+    /// Number of consecutive ticks with a deploy.
+    pub deploy_streak: u64,
+
+    /// The last tick this navigator deployed in.
+    pub last_deploy_tick: u64,
+
+    /// Lifetime excavation hits across all dimensions.
+    pub lifetime_hits: u64,
+
+    /// The season the season stats below belong to.
+    pub season_id: u64,
+
+    /// SOL deployed during the current season.
+    pub season_deployed: u64,
+
+    /// LUXITE mined during the current season.
+    pub season_mined: u64,
+
+    /// Miner accounts this navigator's authority has open.
+    pub open_miners: u64,
+
+    /// Whether `open_miners` counts every miner of the authority (1), or the navigator predates
+    /// the count (0) and can never be closed.
+    pub tracks_miners: u64,
+
+    /// SOL deployed during the season before `season_id`.
+    pub previous_season_deployed: u64,
+
+    /// LUXITE mined during the season before `season_id`.
+    pub previous_season_mined: u64,
 }
 
 impl Navigator {
     pub fn pda(&self) -> (Pubkey, u8) {
         navigator_pda(self.authority)
     }
+
+    /// Returns true if this navigator was referred by another.
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
+
+    /// Returns true if the navigator has earned the given badge.
+    pub fn has_badge(&self, badge: u64) -> bool {
+        self.badges & badge != 0
+    }
+
+    /// Awards a badge.
+    pub fn award_badge(&mut self, badge: u64) {
+        self.badges |= badge;
+    }
+
+    /// Computes XP from lifetime stats.
+    pub fn compute_xp(&self) -> u64 {
+        let sol_deployed = self.lifetime_deployed / ONE_SOL;
+        sol_deployed
+            .saturating_mul(XP_PER_SOL_DEPLOYED)
+            .saturating_add(self.lifetime_hits.saturating_mul(XP_PER_HIT))
+            .saturating_add(self.lifetime_dimensions_discovered.saturating_mul(XP_PER_DISCOVERY))
+    }
+
+    /// Recomputes XP and rank. Returns true if the navigator ranked up.
+    pub fn update_rank(&mut self) -> bool {
+        self.xp = self.compute_xp();
+        let rank = RANK_XP_THRESHOLDS
+            .iter()
+            .rposition(|threshold| self.xp >= *threshold)
+            .unwrap_or(0) as u64;
+        let ranked_up = rank > self.rank;
+        self.rank = self.rank.max(rank);
+        ranked_up
+    }
+
+    /// Records a deploy in the given tick, extending or resetting the deploy streak.
+    pub fn record_deploy(&mut self, tick_id: u64) {
+        if self.deploy_streak > 0 && self.last_deploy_tick == tick_id {
+            return;
+        }
+
+        if self.deploy_streak > 0 && self.last_deploy_tick + 1 == tick_id {
+            self.deploy_streak += 1;
+        } else {
+            self.deploy_streak = 1;
+        }
+        self.last_deploy_tick = tick_id;
+
+        if self.deploy_streak >= DEPLOY_STREAK_BADGE_TICKS {
+            self.award_badge(BADGE_DEPLOY_STREAK);
+        }
+    }
+
+    /// Records an excavation hit.
+    pub fn record_hit(&mut self) {
+        self.lifetime_hits += 1;
+        self.award_badge(BADGE_FIRST_HIT);
+    }
+
+    /// Records a dimension discovery.
+    pub fn record_discovery(&mut self, richness: u32) {
+        self.lifetime_dimensions_discovered += 1;
+        if richness < RICH_DISCOVERY_RICHNESS {
+            self.award_badge(BADGE_RICH_DISCOVERY);
+        }
+    }
+
+    /// Returns the deploy fee for this navigator's rank, in basis points.
+    pub fn deploy_fee_bps(&self) -> u64 {
+        let rank = (self.rank as usize).min(RANK_COUNT - 1);
+        DEPLOY_FEE_BPS.saturating_sub(RANK_DEPLOY_FEE_DISCOUNT_BPS[rank])
+    }
+
+    /// Records checkpointed activity for the season of its excavation. Stats for the current
+    /// and previous season are kept, since excavations expire long before a season ends.
+    /// Activity for an older season is ignored. Returns true if recorded.
+    pub fn record_season(&mut self, season_id: u64, deployed: u64, mined: u64) -> bool {
+        if season_id > self.season_id {
+            if season_id == self.season_id + 1 {
+                self.previous_season_deployed = self.season_deployed;
+                self.previous_season_mined = self.season_mined;
+            } else {
+                self.previous_season_deployed = 0;
+                self.previous_season_mined = 0;
+            }
+            self.season_id = season_id;
+            self.season_deployed = 0;
+            self.season_mined = 0;
+        }
+
+        if season_id == self.season_id {
+            self.season_deployed += deployed;
+            self.season_mined += mined;
+        } else if season_id + 1 == self.season_id {
+            self.previous_season_deployed += deployed;
+            self.previous_season_mined += mined;
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Returns the navigator's score for a season metric.
+    pub fn season_score(&self, season_id: u64, metric: u64) -> u64 {
+        let (deployed, mined) = if season_id == self.season_id {
+            (self.season_deployed, self.season_mined)
+        } else if season_id + 1 == self.season_id {
+            (self.previous_season_deployed, self.previous_season_mined)
+        } else {
+            (0, 0)
+        };
+        match metric {
+            SEASON_METRIC_MINED => mined,
+            _ => deployed,
+        }
+    }
+
+    /// Claims pending referral rewards.
+    pub fn claim_referral(&mut self) -> u64 {
+        let amount = self.referral_rewards_sol;
+        self.referral_rewards_sol = 0;
+        amount
+    }
 }
 
 account!(LocalUniverseAccount, Navigator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_season_keeps_previous_season() {
+        let mut navigator = Navigator::zeroed();
+        assert!(navigator.record_season(3, 100, 0));
+        assert!(navigator.record_season(4, 50, 0));
+
+        // A late checkpoint from the previous season still counts towards it
+        assert!(navigator.record_season(3, 25, 0));
+        assert_eq!(navigator.season_score(3, SEASON_METRIC_DEPLOYED), 125);
+        assert_eq!(navigator.season_score(4, SEASON_METRIC_DEPLOYED), 50);
+
+        assert!(!navigator.record_season(2, 10, 0));
+        assert!(navigator.record_season(6, 10, 0));
+        assert_eq!(navigator.season_score(5, SEASON_METRIC_DEPLOYED), 0);
+        assert_eq!(navigator.season_score(4, SEASON_METRIC_DEPLOYED), 0);
+    }
+}
@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::{DENOMINATOR_BPS, POOL_IDLE_SLOTS, POOL_MAX_DEPLOY_BPS};
+use crate::state::pool_pda;
+use super::LocalUniverseAccount;
+
+/// A mining pool. Members deposit SOL for shares and the operator deploys the pooled SOL as a
+/// single miner owned by this account. Deployed SOL returned by the miner goes back to the
+/// balance; SOL profit and LUXITE accrue per share.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Pool {
+    /// The operator who chooses the dimension and deploys.
+    pub authority: Pubkey,
+
+    /// The pool ID, unique per operator.
+    pub id: u64,
+
+    /// The dimension the pool deploys to.
+    pub dimension_id: u64,
+
+    /// The members' SOL waiting to be deployed.
+    pub balance: u64,
+
+    /// The total shares held by members.
+    pub total_shares: u64,
+
+    /// The SOL deployed and not yet harvested (0 = idle).
+    pub deployed: u64,
+
+    /// The excavation the deployed SOL is in.
+    pub excavation_id: u64,
+
+    /// The cumulative SOL harvested, divided by total shares at time of harvest.
+    pub sol_rewards_factor: Numeric,
+
+    /// The cumulative LUXITE harvested, divided by total shares at time of harvest.
+    pub luxite_rewards_factor: Numeric,
+
+    /// The harvested SOL held for members and not yet claimed.
+    pub rewards_sol: u64,
+
+    /// The total SOL this pool has deployed.
+    pub lifetime_deployed: u64,
+
+    /// The total SOL this pool has harvested.
+    pub lifetime_rewards_sol: u64,
+
+    /// The total LUXITE this pool has harvested.
+    pub lifetime_rewards_luxite: u64,
+
+    /// Unix timestamp when the pool was created.
+    pub created_at: i64,
+
+    /// The slot the pool's last deployment was harvested at.
+    pub harvested_at: u64,
+}
+
+impl Pool {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        pool_pda(self.authority, self.id)
+    }
+
+    /// Returns true if the pool has SOL in an excavation. Deposits and withdrawals wait until
+    /// it is harvested.
+    pub fn is_deployed(&self) -> bool {
+        self.deployed > 0
+    }
+
+    /// Returns true if an idle pool may start a new deployment. Pools stay idle for a while
+    /// after each harvest so members can withdraw.
+    pub fn can_deploy(&self, slot: u64) -> bool {
+        self.is_deployed() || slot >= self.harvested_at + POOL_IDLE_SLOTS
+    }
+
+    /// Returns the most the pool can deploy to its current excavation.
+    pub fn max_deploy(&self) -> u64 {
+        let total = self.balance + self.deployed;
+        let max = ((total as u128 * POOL_MAX_DEPLOY_BPS as u128) / DENOMINATOR_BPS as u128) as u64;
+        max.saturating_sub(self.deployed)
+    }
+
+    /// Returns the SOL backing the shares, idle and deployed.
+    pub fn assets(&self) -> u64 {
+        self.balance + self.deployed
+    }
+
+    /// Returns the shares minted for a deposit, priced by the pool's assets.
+    pub fn shares_for(&self, amount: u64) -> u64 {
+        if self.total_shares == 0 || self.assets() == 0 {
+            return amount;
+        }
+        let shares = (amount as u128 * self.total_shares as u128) / self.assets() as u128;
+        u64::try_from(shares).expect("Share price too low")
+    }
+
+    /// Returns the SOL a number of shares redeems for.
+    pub fn value_of(&self, shares: u64) -> u64 {
+        if self.total_shares == 0 {
+            return 0;
+        }
+        ((shares as u128 * self.assets() as u128) / self.total_shares as u128) as u64
+    }
+
+    /// Settles the current deployment with the SOL its miner returned. Up to the deployed
+    /// amount goes back to the balance; returns the profit left to distribute.
+    pub fn settle(&mut self, sol: u64, slot: u64) -> u64 {
+        let principal = sol.min(self.deployed);
+        self.balance += principal;
+        self.deployed = 0;
+        self.harvested_at = slot;
+        sol - principal
+    }
+
+    /// Distributes harvested SOL and LUXITE to members per share.
+    pub fn distribute(&mut self, sol: u64, luxite: u64) {
+        if self.total_shares == 0 {
+            return;
+        }
+        self.sol_rewards_factor += Numeric::from_fraction(sol, self.total_shares);
+        self.luxite_rewards_factor += Numeric::from_fraction(luxite, self.total_shares);
+        self.rewards_sol += sol;
+        self.lifetime_rewards_sol += sol;
+        self.lifetime_rewards_luxite += luxite;
+    }
+}
+
+account!(LocalUniverseAccount, Pool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(balance: u64, total_shares: u64) -> Pool {
+        let mut pool = Pool::zeroed();
+        pool.balance = balance;
+        pool.total_shares = total_shares;
+        pool
+    }
+
+    #[test]
+    fn shares_for_first_deposit_is_one_to_one() {
+        assert_eq!(Pool::zeroed().shares_for(1_000), 1_000);
+    }
+
+    #[test]
+    fn shares_for_prices_on_balance_and_deployed() {
+        let mut pool = pool(1_000, 4_000);
+        pool.deployed = 1_000;
+        assert_eq!(pool.shares_for(500), 1_000);
+        assert_eq!(pool.value_of(1_000), 500);
+    }
+
+    #[test]
+    fn max_deploy_keeps_half_idle() {
+        let mut pool = pool(1_000, 1_000);
+        assert_eq!(pool.max_deploy(), 500);
+
+        // Deploying the limit leaves nothing more to deploy this excavation
+        pool.balance -= 500;
+        pool.deployed += 500;
+        assert_eq!(pool.max_deploy(), 0);
+    }
+
+    #[test]
+    fn settle_returns_principal_and_profit() {
+        let mut pool = pool(500, 1_000);
+        pool.deployed = 500;
+        assert_eq!(pool.settle(800, 42), 300);
+        assert_eq!(pool.balance, 1_000);
+        assert_eq!(pool.deployed, 0);
+        assert_eq!(pool.harvested_at, 42);
+    }
+
+    #[test]
+    fn can_deploy_after_idle_window() {
+        let mut pool = pool(1_000, 1_000);
+        pool.harvested_at = 1_000;
+        assert!(!pool.can_deploy(1_000 + POOL_IDLE_SLOTS - 1));
+        assert!(pool.can_deploy(1_000 + POOL_IDLE_SLOTS));
+    }
+}
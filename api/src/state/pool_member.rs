@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::{pool_member_pda, Pool};
+use super::LocalUniverseAccount;
+
+/// A member's shares of a mining pool and the rewards they have accrued.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct PoolMember {
+    /// The authority of this member account.
+    pub authority: Pubkey,
+
+    /// The pool this member belongs to.
+    pub pool: Pubkey,
+
+    /// The shares this member holds.
+    pub shares: u64,
+
+    /// The SOL rewards factor last time rewards were updated on this member account.
+    pub sol_rewards_factor: Numeric,
+
+    /// The LUXITE rewards factor last time rewards were updated on this member account.
+    pub luxite_rewards_factor: Numeric,
+
+    /// The amount of SOL this member can claim.
+    pub rewards_sol: u64,
+
+    /// The amount of LUXITE this member can claim.
+    pub rewards_luxite: u64,
+
+    /// The total amount of SOL this member has claimed.
+    pub lifetime_rewards_sol: u64,
+
+    /// The total amount of LUXITE this member has claimed.
+    pub lifetime_rewards_luxite: u64,
+
+    /// The timestamp the last time this member deposited.
+    pub last_deposit_at: i64,
+}
+
+impl PoolMember {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        pool_member_pda(self.pool, self.authority)
+    }
+
+    /// Deposits SOL into the pool's idle balance for shares.
+    pub fn deposit(&mut self, amount: u64, clock: &Clock, pool: &mut Pool) -> u64 {
+        self.update_rewards(pool);
+        let shares = pool.shares_for(amount);
+        self.shares += shares;
+        self.last_deposit_at = clock.unix_timestamp;
+        pool.total_shares += shares;
+        pool.balance += amount;
+        shares
+    }
+
+    /// Redeems shares for their part of the pool's idle balance.
+    pub fn withdraw(&mut self, shares: u64, pool: &mut Pool) -> u64 {
+        self.update_rewards(pool);
+        let shares = self.shares.min(shares);
+        let amount = pool.value_of(shares);
+        self.shares -= shares;
+        pool.total_shares -= shares;
+        pool.balance -= amount;
+        amount
+    }
+
+    /// Claims all accrued SOL and LUXITE rewards.
+    pub fn claim(&mut self, pool: &mut Pool) -> (u64, u64) {
+        self.update_rewards(pool);
+        let sol = self.rewards_sol.min(pool.rewards_sol);
+        let luxite = self.rewards_luxite;
+        self.rewards_sol = 0;
+        self.rewards_luxite = 0;
+        self.lifetime_rewards_sol += sol;
+        self.lifetime_rewards_luxite += luxite;
+        pool.rewards_sol -= sol;
+        (sol, luxite)
+    }
+
+    pub fn update_rewards(&mut self, pool: &Pool) {
+        if pool.sol_rewards_factor > self.sol_rewards_factor {
+            let accumulated_rewards = pool.sol_rewards_factor - self.sol_rewards_factor;
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.shares);
+            self.rewards_sol += personal_rewards.to_u64();
+        }
+        self.sol_rewards_factor = pool.sol_rewards_factor;
+
+        if pool.luxite_rewards_factor > self.luxite_rewards_factor {
+            let accumulated_rewards = pool.luxite_rewards_factor - self.luxite_rewards_factor;
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.shares);
+            self.rewards_luxite += personal_rewards.to_u64();
+        }
+        self.luxite_rewards_factor = pool.luxite_rewards_factor;
+    }
+}
+
+account!(LocalUniverseAccount, PoolMember);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member() -> PoolMember {
+        PoolMember::zeroed()
+    }
+
+    #[test]
+    fn update_rewards_accrues_per_share() {
+        let mut pool = Pool::zeroed();
+        let clock = Clock::default();
+        let mut a = member();
+        let mut b = member();
+        a.deposit(600, &clock, &mut pool);
+        b.deposit(400, &clock, &mut pool);
+
+        pool.distribute(500, 1_000);
+        a.update_rewards(&pool);
+        b.update_rewards(&pool);
+        assert_eq!((a.rewards_sol, a.rewards_luxite), (300, 600));
+        assert_eq!((b.rewards_sol, b.rewards_luxite), (200, 400));
+
+        // Updating again without new rewards changes nothing
+        a.update_rewards(&pool);
+        assert_eq!(a.rewards_sol, 300);
+    }
+
+    #[test]
+    fn deposit_after_losing_round_is_not_diluted() {
+        let mut pool = Pool::zeroed();
+        let clock = Clock::default();
+        let mut a = member();
+        a.deposit(1_000, &clock, &mut pool);
+
+        // Deploy the limit and lose it
+        let amount = pool.max_deploy();
+        pool.balance -= amount;
+        pool.deployed += amount;
+        assert_eq!(pool.settle(0, 0), 0);
+        assert_eq!(pool.balance, 500);
+
+        // A new member's shares redeem for what they deposited
+        let mut b = member();
+        let shares = b.deposit(500, &clock, &mut pool);
+        assert_eq!(pool.value_of(shares), 500);
+        assert_eq!(b.withdraw(shares, &mut pool), 500);
+        assert_eq!(a.withdraw(a.shares, &mut pool), 500);
+        assert_eq!(pool.balance, 0);
+    }
+
+    #[test]
+    fn new_members_do_not_earn_past_rewards() {
+        let mut pool = Pool::zeroed();
+        let clock = Clock::default();
+        let mut a = member();
+        a.deposit(1_000, &clock, &mut pool);
+        pool.distribute(500, 0);
+
+        let mut b = member();
+        b.sol_rewards_factor = pool.sol_rewards_factor;
+        b.luxite_rewards_factor = pool.luxite_rewards_factor;
+        b.deposit(1_000, &clock, &mut pool);
+        b.update_rewards(&pool);
+        assert_eq!(b.rewards_sol, 0);
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::*;
+use crate::state::proposal_pda;
+use super::LocalUniverseAccount;
+
+/// A governance proposal to change a config parameter. Stakers vote with their staked
+/// balance, and a passed proposal can be executed once its timelock has elapsed, until the
+/// execution window closes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Proposal {
+    /// The proposal ID.
+    pub id: u64,
+
+    /// The authority that created the proposal.
+    pub proposer: Pubkey,
+
+    /// The config parameter to change (PARAM_*).
+    pub param: u64,
+
+    /// The proposed value.
+    pub value: u64,
+
+    /// Unix timestamp when the proposal was created.
+    pub created_at: i64,
+
+    /// Unix timestamp when voting closes.
+    pub voting_ends_at: i64,
+
+    /// Unix timestamp after which a passed proposal can be executed.
+    pub executable_at: i64,
+
+    /// The staked LUXITE voting in favor.
+    pub votes_for: u64,
+
+    /// The staked LUXITE voting against.
+    pub votes_against: u64,
+
+    /// The staked LUXITE in favor required to pass, snapshot at creation.
+    pub quorum: u64,
+
+    /// Whether the proposal has been executed (1 = executed).
+    pub executed: u64,
+
+    /// Whether the proposal has been vetoed by the admin (1 = vetoed).
+    pub vetoed: u64,
+}
+
+impl Proposal {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        proposal_pda(self.id)
+    }
+
+    /// Returns the quorum for a total staked amount.
+    pub fn quorum_of(total_staked: u64) -> u64 {
+        ((total_staked as u128 * GOVERNANCE_QUORUM_BPS as u128) / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// Returns true if voting is open.
+    pub fn is_voting(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.voting_ends_at
+    }
+
+    /// Returns the Unix timestamp after which a passed proposal can no longer be executed.
+    pub fn expires_at(&self) -> i64 {
+        self.executable_at + GOVERNANCE_EXECUTION_WINDOW
+    }
+
+    /// Returns true if the execution window has closed.
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp >= self.expires_at()
+    }
+
+    /// Returns true if the proposal has been executed or vetoed.
+    pub fn is_closed(&self) -> bool {
+        self.executed > 0 || self.vetoed > 0
+    }
+
+    /// Returns true if the vote passed.
+    pub fn passed(&self) -> bool {
+        self.votes_for > self.votes_against && self.votes_for >= self.quorum
+    }
+
+    /// Records a vote weighted by staked balance.
+    pub fn vote(&mut self, support: bool, weight: u64) {
+        if support {
+            self.votes_for += weight;
+        } else {
+            self.votes_against += weight;
+        }
+    }
+}
+
+account!(LocalUniverseAccount, Proposal);
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::*;
+use crate::state::season_pda;
+use super::LocalUniverseAccount;
+
+/// A competitive season tied to a grid epoch. Ranks navigators by SOL deployed or LUXITE
+/// mined and pays a LUXITE prize pool to the leaderboard at settlement.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Season {
+    /// The season ID (equal to the grid epoch ID).
+    pub id: u64,
+
+    /// The metric navigators are ranked by (SEASON_METRIC_*).
+    pub metric: u64,
+
+    /// The LUXITE prize pool reserved from the treasury.
+    pub prize_pool: u64,
+
+    /// Whether the season has been settled (1 = settled).
+    pub settled: u64,
+
+    /// The leaderboard, sorted by score descending.
+    pub leaderboard: [SeasonEntry; SEASON_LEADERBOARD_SIZE],
+
+    /// Buffer a (placeholder).
+    pub buffer_a: u64,
+
+    /// Buffer b (placeholder).
+    pub buffer_b: u64,
+}
+
+/// A navigator's position on a season leaderboard.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct SeasonEntry {
+    /// The navigator authority.
+    pub authority: Pubkey,
+
+    /// The navigator's season score.
+    pub score: u64,
+}
+
+impl Season {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        season_pda(self.id)
+    }
+
+    /// Returns the first tick of this season.
+    pub fn start_tick(&self) -> u64 {
+        self.id * EPOCH_DURATION_TICKS
+    }
+
+    /// Returns the first tick at which this season can be settled.
+    pub fn settle_tick(&self) -> u64 {
+        (self.id + 1) * EPOCH_DURATION_TICKS + SEASON_SETTLEMENT_DELAY_TICKS
+    }
+
+    /// Returns true if the season has been settled.
+    pub fn is_settled(&self) -> bool {
+        self.settled > 0
+    }
+
+    /// Records a navigator's score, keeping the leaderboard sorted by score descending.
+    pub fn record(&mut self, authority: Pubkey, score: u64) {
+        if score == 0 {
+            return;
+        }
+
+        let slot = match self.leaderboard.iter().position(|e| e.authority == authority) {
+            Some(i) => i,
+            None => {
+                // Replace the lowest entry if the score beats it
+                let last = SEASON_LEADERBOARD_SIZE - 1;
+                if score <= self.leaderboard[last].score {
+                    return;
+                }
+                last
+            }
+        };
+
+        self.leaderboard[slot] = SeasonEntry { authority, score };
+
+        // Bubble the entry up to its position
+        let mut i = slot;
+        while i > 0 && self.leaderboard[i].score > self.leaderboard[i - 1].score {
+            self.leaderboard.swap(i, i - 1);
+            i -= 1;
+        }
+    }
+
+    /// Returns the number of ranked navigators.
+    pub fn winners(&self) -> usize {
+        self.leaderboard.iter().filter(|e| e.score > 0).count()
+    }
+
+    /// Returns the prize for a leaderboard position.
+    pub fn prize(&self, position: usize) -> u64 {
+        self.prize_pool
+            .checked_mul(SEASON_PRIZE_BPS[position])
+            .unwrap()
+            .checked_div(DENOMINATOR_BPS)
+            .unwrap()
+    }
+}
+
+account!(LocalUniverseAccount, Season);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_keeps_leaderboard_sorted() {
+        let mut season = Season::zeroed();
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        season.record(a, 10);
+        season.record(b, 20);
+        assert_eq!(season.leaderboard[0].authority, b);
+        assert_eq!(season.leaderboard[1].authority, a);
+
+        // Updating an existing entry moves it rather than duplicating it
+        season.record(a, 30);
+        assert_eq!(season.leaderboard[0], SeasonEntry { authority: a, score: 30 });
+        assert_eq!(season.leaderboard[1], SeasonEntry { authority: b, score: 20 });
+        assert_eq!(season.winners(), 2);
+    }
+
+    #[test]
+    fn record_replaces_lowest_entry_when_full() {
+        let mut season = Season::zeroed();
+        for score in 1..=SEASON_LEADERBOARD_SIZE as u64 {
+            season.record(Pubkey::new_unique(), score * 10);
+        }
+        let last = SEASON_LEADERBOARD_SIZE - 1;
+        assert_eq!(season.leaderboard[last].score, 10);
+
+        // Scores that do not beat the lowest entry are ignored
+        let low = Pubkey::new_unique();
+        season.record(low, 10);
+        assert!(season.leaderboard.iter().all(|e| e.authority != low));
+
+        let high = Pubkey::new_unique();
+        season.record(high, 15);
+        assert_eq!(season.leaderboard[last], SeasonEntry { authority: high, score: 15 });
+        assert_eq!(season.winners(), SEASON_LEADERBOARD_SIZE);
+    }
+
+    #[test]
+    fn record_ignores_zero_scores() {
+        let mut season = Season::zeroed();
+        season.record(Pubkey::new_unique(), 0);
+        assert_eq!(season.winners(), 0);
+    }
+}
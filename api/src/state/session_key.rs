@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::session_key_pda;
+use super::LocalUniverseAccount;
+
+/// An ephemeral key allowed to act for an authority until it expires. Deploys made with the
+/// key are paid from SOL escrowed in this account, up to the spend limit.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct SessionKey {
+    /// The authority who created this session.
+    pub authority: Pubkey,
+
+    /// The ephemeral key allowed to sign.
+    pub key: Pubkey,
+
+    /// Bitmask of SESSION_PERMISSION_* flags.
+    pub permissions: u64,
+
+    /// Unix timestamp after which the key can no longer sign.
+    pub expires_at: i64,
+
+    /// The total SOL the key can deploy (escrowed at creation).
+    pub spend_limit: u64,
+
+    /// The SOL the key has deployed so far.
+    pub spent: u64,
+
+    /// Unix timestamp when the session was created.
+    pub created_at: i64,
+}
+
+impl SessionKey {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        session_key_pda(self.authority, self.key)
+    }
+
+    /// Returns true if the session has expired.
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp >= self.expires_at
+    }
+
+    /// Returns true if the session grants a permission.
+    pub fn allows(&self, permission: u64) -> bool {
+        self.permissions & permission == permission
+    }
+
+    /// Returns the SOL the key can still deploy.
+    pub fn remaining(&self) -> u64 {
+        self.spend_limit.saturating_sub(self.spent)
+    }
+}
+
+account!(LocalUniverseAccount, SessionKey);
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
+use crate::consts::*;
 use crate::state::{stake_pda, Treasury};
 use super::LocalUniverseAccount;
 
@@ -34,19 +35,61 @@ pub struct Stake {
     /// The timestamp the last time this staker withdrew.
     pub last_withdraw_at: i64,
 
-    /// Buffer a (placeholder).
-    pub buffer_a: u64,
+    /// The timestamp until which the balance cannot be withdrawn.
+    pub unlock_at: i64,
 
-    /// Buffer b (placeholder).
-    pub buffer_b: u64,
+    /// The reward multiplier of the current lock, in basis points.
+    pub multiplier_bps: u64,
 
-    /// Buffer c (placeholder).
-    pub buffer_c: u64,
+    /// The balance weighted by the reward multiplier.
+    pub weighted_balance: u64,
+
+    /// The position ID of this stake account, unique per authority.
+    pub id: u64,
+
+    /// The withdrawn LUXITE waiting out the unbonding period. Earns no yield.
+    pub unbonding_balance: u64,
+
+    /// The timestamp after which the unbonding balance can be withdrawn.
+    pub unbonding_at: i64,
+
+    /// The SOL rewards factor last time rewards were updated on this stake account.
+    pub sol_rewards_factor: Numeric,
+
+    /// The amount of SOL this staker can claim.
+    pub sol_rewards: u64,
+
+    /// The total amount of SOL this staker has earned over its lifetime.
+    pub lifetime_sol_rewards: u64,
+
+    /// A key allowed to claim rewards on behalf of the authority (default = none).
+    pub claim_delegate: Pubkey,
+
+    /// The wallet that receives claimed rewards (default = the authority).
+    pub recipient: Pubkey,
+
+    /// The timestamp until which the balance cannot be withdrawn, as it counts towards a vote.
+    pub vote_locked_until: i64,
 }
 
 impl Stake {
     pub fn pda(&self) -> (Pubkey, u8) {
-        stake_pda(self.authority)
+        stake_pda(self.authority, self.id)
+    }
+
+    /// Returns true if a key may claim this position's rewards.
+    pub fn can_claim(&self, key: &Pubkey) -> bool {
+        *key == self.authority
+            || (self.claim_delegate != Pubkey::default() && *key == self.claim_delegate)
+    }
+
+    /// Returns the wallet that receives this position's claimed rewards.
+    pub fn claim_recipient(&self) -> Pubkey {
+        if self.recipient == Pubkey::default() {
+            self.authority
+        } else {
+            self.recipient
+        }
     }
 
     pub fn claim(&mut self, amount: u64, clock: &Clock, treasury: &Treasury) -> u64 {
@@ -57,12 +100,14 @@ impl Stake {
         amount
     }
 
-    pub fn compound(&mut self, treasury: &mut Treasury) -> u64 {
+    pub fn compound(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
         self.update_rewards(treasury);
         let amount = self.rewards;
         self.rewards = 0;
         self.balance += amount;
+        self.last_deposit_at = clock.unix_timestamp;
         treasury.total_staked += amount;
+        self.sync_weight(clock, treasury);
         amount
     }
 
@@ -78,29 +123,110 @@ impl Stake {
         self.balance += amount;
         self.last_deposit_at = clock.unix_timestamp;
         treasury.total_staked += amount;
+        self.sync_weight(clock, treasury);
         amount
     }
 
-    pub fn withdraw(&mut self, amount: u64, clock: &Clock, treasury: &mut Treasury) -> u64 {
+    /// Moves an amount of the balance into unbonding, restarting the unbonding period.
+    pub fn withdraw(
+        &mut self,
+        amount: u64,
+        clock: &Clock,
+        treasury: &mut Treasury,
+        unbonding_period: i64,
+    ) -> u64 {
         self.update_rewards(treasury);
         let amount = self.balance.min(amount);
         self.balance -= amount;
+        self.unbonding_balance += amount;
+        self.unbonding_at = clock.unix_timestamp + unbonding_period;
         self.last_withdraw_at = clock.unix_timestamp;
         treasury.total_staked -= amount;
+        self.sync_weight(clock, treasury);
+        amount
+    }
+
+    /// Claims all pending SOL rewards, capped at the SOL held for stakers against rounding.
+    pub fn claim_sol(&mut self, treasury: &mut Treasury) -> u64 {
+        self.update_rewards(treasury);
+        let amount = self.sol_rewards.min(treasury.stake_sol_balance);
+        self.sol_rewards = 0;
+        treasury.stake_sol_balance -= amount;
+        amount
+    }
+
+    /// Releases the full unbonding balance.
+    pub fn complete_withdraw(&mut self) -> u64 {
+        let amount = self.unbonding_balance;
+        self.unbonding_balance = 0;
         amount
     }
 
+    /// Returns true if the unbonding balance cannot be withdrawn yet.
+    pub fn is_unbonding(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.unbonding_at
+    }
+
+    /// Returns true if the balance is locked.
+    pub fn is_locked(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.unlock_at
+    }
+
+    /// Returns true if the balance counts towards a proposal still being voted on.
+    pub fn is_vote_locked(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.vote_locked_until
+    }
+
+    /// Returns the lock option matching the current multiplier.
+    pub fn lock_option(&self) -> usize {
+        STAKE_LOCK_MULTIPLIERS_BPS
+            .iter()
+            .rposition(|m| *m == self.multiplier_bps)
+            .unwrap_or(0)
+    }
+
+    /// Locks the position for a lock option. Locks can only be extended, never shortened.
+    pub fn lock(&mut self, option: usize, clock: &Clock, treasury: &mut Treasury) {
+        self.update_rewards(treasury);
+        let unlock_at = clock.unix_timestamp + STAKE_LOCK_DURATIONS[option];
+        if unlock_at > self.unlock_at {
+            self.unlock_at = unlock_at;
+        }
+        self.multiplier_bps = self.multiplier_bps.max(STAKE_LOCK_MULTIPLIERS_BPS[option]);
+        self.sync_weight(clock, treasury);
+    }
+
+    /// Recomputes the weighted balance, dropping the multiplier once the lock has expired.
+    pub fn sync_weight(&mut self, clock: &Clock, treasury: &mut Treasury) {
+        if !self.is_locked(clock) || self.multiplier_bps == 0 {
+            self.multiplier_bps = DENOMINATOR_BPS;
+        }
+        let weighted_balance = ((self.balance as u128 * self.multiplier_bps as u128)
+            / DENOMINATOR_BPS as u128) as u64;
+        treasury.total_weighted_stake =
+            treasury.total_weighted_stake + weighted_balance - self.weighted_balance;
+        self.weighted_balance = weighted_balance;
+    }
+
     pub fn update_rewards(&mut self, treasury: &Treasury) {
         if treasury.stake_rewards_factor > self.rewards_factor {
             let accumulated_rewards = treasury.stake_rewards_factor - self.rewards_factor;
             if accumulated_rewards < Numeric::ZERO {
                 panic!("Accumulated rewards is negative");
             }
-            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.balance);
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.weighted_balance);
             self.rewards += personal_rewards.to_u64();
             self.lifetime_rewards += personal_rewards.to_u64();
         }
         self.rewards_factor = treasury.stake_rewards_factor;
+
+        if treasury.stake_sol_rewards_factor > self.sol_rewards_factor {
+            let accumulated_rewards = treasury.stake_sol_rewards_factor - self.sol_rewards_factor;
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.weighted_balance);
+            self.sol_rewards += personal_rewards.to_u64();
+            self.lifetime_sol_rewards += personal_rewards.to_u64();
+        }
+        self.sol_rewards_factor = treasury.stake_sol_rewards_factor;
     }
 }
 
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
+use crate::consts::DENOMINATOR_BPS;
 use crate::state::treasury_pda;
 use super::LocalUniverseAccount;
 
@@ -35,20 +36,34 @@ pub struct Treasury {
     /// The total amount of LUXITE burned from buybacks.
     pub total_burned: u64,
 
-    /// Buffer a (placeholder).
-    pub buffer_a: u64,
+    /// The current total of staked LUXITE weighted by lock multipliers.
+    pub total_weighted_stake: u64,
 
-    /// Buffer b (placeholder).
-    pub buffer_b: u64,
+    /// The cumulative SOL distributed to stakers, divided by total weighted stake at time of
+    /// distribution.
+    pub stake_sol_rewards_factor: Numeric,
 
-    /// Buffer c (placeholder).
-    pub buffer_c: u64,
+    /// The amount of SOL held for stakers and not yet claimed.
+    pub stake_sol_balance: u64,
 }
 
 impl Treasury {
     pub fn pda() -> (Pubkey, u8) {
         treasury_pda()
     }
+
+    /// Collects SOL revenue, streaming a share to stakers and keeping the rest for buybacks.
+    pub fn collect_sol(&mut self, amount: u64, stake_sol_share_bps: u64) {
+        let mut stake_amount = 0;
+        if self.total_weighted_stake > 0 {
+            stake_amount = ((amount as u128 * stake_sol_share_bps.min(DENOMINATOR_BPS) as u128)
+                / DENOMINATOR_BPS as u128) as u64;
+            self.stake_sol_rewards_factor +=
+                Numeric::from_fraction(stake_amount, self.total_weighted_stake);
+            self.stake_sol_balance += stake_amount;
+        }
+        self.sol_balance += amount - stake_amount;
+    }
 }
 
 account!(LocalUniverseAccount, Treasury);
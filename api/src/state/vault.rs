@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+use crate::consts::*;
+use crate::state::vault_pda;
+use super::LocalUniverseAccount;
+
+/// Vault is a singleton account that stakes pooled LUXITE through a single stake position
+/// and issues a transferable receipt token (stLUXITE) against it. Yield compounds into the
+/// position, so each receipt redeems for a growing amount of LUXITE.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Vault {
+    /// The receipt token mint.
+    pub mint: Pubkey,
+
+    /// The stake position holding the vault's LUXITE.
+    pub stake: Pubkey,
+
+    /// The total amount of LUXITE deposited into the vault.
+    pub total_deposited: u64,
+
+    /// The total amount of LUXITE redeemed from the vault.
+    pub total_redeemed: u64,
+
+    /// The total amount of redemption fees left in the vault.
+    pub total_fees: u64,
+}
+
+impl Vault {
+    pub fn pda() -> (Pubkey, u8) {
+        vault_pda()
+    }
+
+    /// Returns the receipt tokens minted for a deposit, given the vault's assets and receipt supply.
+    pub fn shares_for(amount: u64, assets: u64, supply: u64) -> u64 {
+        if supply == 0 || assets == 0 {
+            return amount;
+        }
+        ((amount as u128 * supply as u128) / assets as u128) as u64
+    }
+
+    /// Returns the LUXITE backing an amount of receipt tokens, before the redemption fee.
+    pub fn assets_for(shares: u64, assets: u64, supply: u64) -> u64 {
+        if supply == 0 {
+            return 0;
+        }
+        ((shares as u128 * assets as u128) / supply as u128) as u64
+    }
+
+    /// Returns the redemption fee on an amount of LUXITE.
+    pub fn redeem_fee(amount: u64) -> u64 {
+        amount * VAULT_REDEEM_FEE_BPS / DENOMINATOR_BPS
+    }
+}
+
+account!(LocalUniverseAccount, Vault);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_for_first_deposit_is_one_to_one() {
+        assert_eq!(Vault::shares_for(1_000, 0, 0), 1_000);
+        assert_eq!(Vault::shares_for(1_000, 500, 0), 1_000);
+    }
+
+    #[test]
+    fn shares_for_prices_at_exchange_rate() {
+        // 2,000 LUXITE backing 1,000 receipts: each receipt is worth 2 LUXITE
+        assert_eq!(Vault::shares_for(500, 2_000, 1_000), 250);
+        assert_eq!(Vault::assets_for(250, 2_500, 1_250), 500);
+    }
+
+    #[test]
+    fn shares_for_round_trip_never_gains() {
+        let (assets, supply) = (3_000_001, 1_999_999);
+        let shares = Vault::shares_for(12_345, assets, supply);
+        let amount = Vault::assets_for(shares, assets + 12_345, supply + shares);
+        assert!(amount <= 12_345);
+    }
+
+    #[test]
+    fn redeem_fee_is_bps_of_amount() {
+        assert_eq!(Vault::redeem_fee(DENOMINATOR_BPS), VAULT_REDEEM_FEE_BPS);
+        assert_eq!(Vault::redeem_fee(0), 0);
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::vault_redemption_pda;
+use super::LocalUniverseAccount;
+
+/// LUXITE redeemed from the staking vault that is waiting out the unbonding period. The
+/// receipt tokens are already burned, so the amount earns no yield while it waits.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct VaultRedemption {
+    /// The authority that redeemed.
+    pub authority: Pubkey,
+
+    /// The LUXITE waiting out the unbonding period.
+    pub amount: u64,
+
+    /// The timestamp after which the amount can be withdrawn.
+    pub unbonding_at: i64,
+}
+
+impl VaultRedemption {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        vault_redemption_pda(self.authority)
+    }
+
+    /// Returns true if the amount cannot be withdrawn yet.
+    pub fn is_unbonding(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.unbonding_at
+    }
+}
+
+account!(LocalUniverseAccount, VaultRedemption);
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::vote_pda;
+use super::LocalUniverseAccount;
+
+/// A stake position's vote on a governance proposal. Its existence prevents the position from
+/// voting twice.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Vote {
+    /// The proposal voted on.
+    pub proposal_id: u64,
+
+    /// The stake position that voted.
+    pub stake: Pubkey,
+
+    /// The authority of the stake position.
+    pub authority: Pubkey,
+
+    /// Whether the vote was in favor (1 = for, 0 = against).
+    pub support: u64,
+
+    /// The staked balance counted.
+    pub weight: u64,
+
+    /// Unix timestamp when the vote was cast.
+    pub voted_at: i64,
+}
+
+impl Vote {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        vote_pda(self.proposal_id, self.stake)
+    }
+}
+
+account!(LocalUniverseAccount, Vote);
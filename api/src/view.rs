@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+/// A miner's claimable rewards, including what a checkpoint of its last excavation would credit.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct MinerRewardsView {
+    /// The dimension the miner is on.
+    pub dimension_id: u64,
+
+    /// The excavation the miner last played in.
+    pub excavation_id: u64,
+
+    /// The checkpoint status of that excavation (see CHECKPOINT_STATUS_*).
+    pub status: u64,
+
+    /// The SOL a checkpoint would credit.
+    pub checkpoint_sol: u64,
+
+    /// The LUXITE a checkpoint would credit, including the stake boost.
+    pub checkpoint_luxite: u64,
+
+    /// The SOL the miner can claim after checkpointing.
+    pub rewards_sol: u64,
+
+    /// The mined LUXITE the miner can claim after checkpointing (before the claim fee).
+    pub rewards_luxite: u64,
+
+    /// The LUXITE the miner has earned from other miners' claim fees.
+    pub refined_luxite: u64,
+
+    /// The LUXITE a claim would pay out after the claim fee.
+    pub claimable_luxite: u64,
+}
+
+/// A stake position's pending yield.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct StakeYieldView {
+    /// The position ID.
+    pub id: u64,
+
+    /// The balance of staked LUXITE.
+    pub balance: u64,
+
+    /// The balance weighted by the reward multiplier.
+    pub weighted_balance: u64,
+
+    /// The LUXITE yield the position can claim.
+    pub rewards: u64,
+
+    /// The SOL yield the position can claim.
+    pub sol_rewards: u64,
+
+    /// The LUXITE waiting out the unbonding period.
+    pub unbonding_balance: u64,
+}
+
+/// The odds and, once processed, the outcome of an excavation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ExcavationView {
+    /// The dimension the excavation is on.
+    pub dimension_id: u64,
+
+    /// The tick the excavation is for.
+    pub tick_id: u64,
+
+    /// The total SOL deployed in the excavation.
+    pub total_deployed: u64,
+
+    /// The dimension's richness at the drill's current depth.
+    pub richness: u64,
+
+    /// The richness rolled against, after the low-deploy penalty.
+    pub effective_richness: u64,
+
+    /// The probability of a hit, in basis points.
+    pub hit_probability_bps: u64,
+
+    /// Whether the excavation has been processed (1) or not (0).
+    pub processed: u64,
+
+    /// The roll drawn from the slot hash (0 if unprocessed).
+    pub roll: u64,
+
+    /// Whether the excavation hit (1) or missed (0).
+    pub did_hit: u64,
+}
+
+macro_rules! view {
+    ($struct_name:ident) => {
+        impl $struct_name {
+            pub fn to_bytes(&self) -> &[u8] {
+                bytemuck::bytes_of(self)
+            }
+
+            /// Decodes a view from transaction return data, which has trailing zero bytes
+            /// stripped.
+            pub fn from_bytes(data: &[u8]) -> Option<Self> {
+                let mut bytes = [0u8; std::mem::size_of::<$struct_name>()];
+                if data.len() > bytes.len() {
+                    return None;
+                }
+                bytes[..data.len()].copy_from_slice(data);
+                Some(bytemuck::pod_read_unaligned(&bytes))
+            }
+        }
+    };
+}
+
+view!(MinerRewardsView);
+view!(StakeYieldView);
+view!(ExcavationView);
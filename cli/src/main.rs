@@ -1,7 +1,13 @@
 use std::str::FromStr;
 
+use base64::Engine;
 use localuniverse_api::prelude::*;
-use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
 use solana_sdk::{
     compute_budget::ComputeBudgetInstruction,
     native_token::lamports_to_sol,
@@ -29,28 +35,77 @@ async fn main() {
         "grid" => log_grid(&rpc).await.unwrap(),
         "treasury" => log_treasury(&rpc).await.unwrap(),
         "dimension" => log_dimension(&rpc).await.unwrap(),
+        "dimensions" => log_dimensions(&rpc).await.unwrap(),
         "drill" => log_drill(&rpc).await.unwrap(),
         "excavation" => log_excavation(&rpc).await.unwrap(),
         "miner" => log_miner(&rpc, &payer).await.unwrap(),
         "navigator" => log_navigator(&rpc, &payer).await.unwrap(),
         "stake" => log_stake(&rpc, &payer).await.unwrap(),
+        "stakes" => log_stakes(&rpc, &payer).await.unwrap(),
+        "season" => log_season(&rpc).await.unwrap(),
+        "proposal" => log_proposal(&rpc).await.unwrap(),
+        "vault" => log_vault(&rpc, &payer).await.unwrap(),
+        "miner_rewards" => log_miner_rewards(&rpc, &payer).await.unwrap(),
+        "stake_yield" => log_stake_yield(&rpc, &payer).await.unwrap(),
+        "excavation_odds" => log_excavation_odds(&rpc, &payer).await.unwrap(),
         "initialize" => initialize(&rpc, &payer).await.unwrap(),
+        "initialize_vault" => initialize_vault(&rpc, &payer).await.unwrap(),
         "scan" => scan(&rpc, &payer).await.unwrap(),
+        "refine" => refine(&rpc, &payer).await.unwrap(),
         "deploy" => deploy(&rpc, &payer).await.unwrap(),
         "tick" => tick(&rpc, &payer).await.unwrap(),
         "excavate" => excavate(&rpc, &payer).await.unwrap(),
         "checkpoint" => checkpoint(&rpc, &payer).await.unwrap(),
         "close" => close(&rpc, &payer).await.unwrap(),
+        "close_many" => close_many(&rpc, &payer).await.unwrap(),
         "claim_luxite" => claim_luxite(&rpc, &payer).await.unwrap(),
         "claim_sol" => claim_sol(&rpc, &payer).await.unwrap(),
+        "set_miner_delegate" => set_miner_delegate(&rpc, &payer).await.unwrap(),
+        "claim_all" => claim_all(&rpc, &payer).await.unwrap(),
+        "close_miner" => close_miner(&rpc, &payer).await.unwrap(),
         "deposit" => deposit(&rpc, &payer).await.unwrap(),
         "withdraw" => withdraw(&rpc, &payer).await.unwrap(),
+        "complete_withdraw" => complete_withdraw(&rpc, &payer).await.unwrap(),
         "claim_yield" => claim_yield(&rpc, &payer).await.unwrap(),
+        "claim_stake_sol" => claim_stake_sol(&rpc, &payer).await.unwrap(),
+        "set_stake_delegate" => set_stake_delegate(&rpc, &payer).await.unwrap(),
+        "poke_stake" => poke_stake(&rpc, &payer).await.unwrap(),
+        "vault_deposit" => vault_deposit(&rpc, &payer).await.unwrap(),
+        "vault_redeem" => vault_redeem(&rpc, &payer).await.unwrap(),
+        "complete_redeem" => complete_redeem(&rpc, &payer).await.unwrap(),
+        "pool" => log_pool(&rpc, &payer).await.unwrap(),
+        "create_pool" => create_pool(&rpc, &payer).await.unwrap(),
+        "set_pool_dimension" => set_pool_dimension(&rpc, &payer).await.unwrap(),
+        "pool_deposit" => pool_deposit(&rpc, &payer).await.unwrap(),
+        "pool_withdraw" => pool_withdraw(&rpc, &payer).await.unwrap(),
+        "pool_deploy" => pool_deploy(&rpc, &payer).await.unwrap(),
+        "pool_harvest" => pool_harvest(&rpc, &payer).await.unwrap(),
+        "pool_claim" => pool_claim(&rpc, &payer).await.unwrap(),
         "automation" => log_automation(&rpc, &payer).await.unwrap(),
         "automate" => automate(&rpc, &payer).await.unwrap(),
         "reload-sol" => reload_sol(&rpc, &payer).await.unwrap(),
+        "session" => log_session(&rpc, &payer).await.unwrap(),
+        "create_session" => create_session(&rpc, &payer).await.unwrap(),
+        "revoke_session" => revoke_session(&rpc, &payer).await.unwrap(),
         "fund_treasury" => fund_treasury(&rpc, &payer).await.unwrap(),
         "set_scan_fee" => set_scan_fee(&rpc, &payer).await.unwrap(),
+        "set_referrer" => set_referrer(&rpc, &payer).await.unwrap(),
+        "claim_referral" => claim_referral(&rpc, &payer).await.unwrap(),
+        "close_navigator" => close_navigator(&rpc, &payer).await.unwrap(),
+        "set_referral_fee" => set_referral_fee(&rpc, &payer).await.unwrap(),
+        "open_season" => open_season(&rpc, &payer).await.unwrap(),
+        "set_miss_rebate" => set_miss_rebate(&rpc, &payer).await.unwrap(),
+        "set_deploy_caps" => set_deploy_caps(&rpc, &payer).await.unwrap(),
+        "set_deploy_window" => set_deploy_window(&rpc, &payer).await.unwrap(),
+        "set_unbonding_period" => set_unbonding_period(&rpc, &payer).await.unwrap(),
+        "set_stake_sol_share" => set_stake_sol_share(&rpc, &payer).await.unwrap(),
+        "set_stake_boost" => set_stake_boost(&rpc, &payer).await.unwrap(),
+        "settle_season" => settle_season(&rpc, &payer).await.unwrap(),
+        "propose" => propose(&rpc, &payer).await.unwrap(),
+        "vote" => cast_vote(&rpc, &payer).await.unwrap(),
+        "execute_proposal" => execute_proposal(&rpc, &payer).await.unwrap(),
+        "veto_proposal" => veto_proposal(&rpc, &payer).await.unwrap(),
+        "migrate" => migrate(&rpc, &payer).await.unwrap(),
         "keys" => keys().await.unwrap(),
         _ => panic!("Invalid command"),
     };
@@ -77,6 +132,34 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  admin: {}", config.admin);
     println!("  fee_collector: {}", config.fee_collector);
     println!("  scan_fee: {} SOL", lamports_to_sol(config.scan_fee));
+    println!("  next_dimension_id: {}", config.next_dimension_id);
+    println!("  dimension_count: {}", config.dimension_count);
+    println!("  referral_fee_bps: {}", config.referral_fee_bps);
+    println!("  miss_rebate_bps: {}", config.miss_rebate_bps);
+    println!(
+        "  miss_rebate_luxite_rate: {} LUXITE/SOL",
+        amount_to_ui_amount(config.miss_rebate_luxite_rate, TOKEN_DECIMALS)
+    );
+    println!("  max_miner_deploy: {} SOL", lamports_to_sol(config.max_miner_deploy));
+    println!(
+        "  max_excavation_deploy: {} SOL",
+        lamports_to_sol(config.max_excavation_deploy)
+    );
+    println!(
+        "  luxite_curve: {}",
+        if config.luxite_curve == LUXITE_CURVE_SQRT { "sqrt" } else { "linear" }
+    );
+    println!("  deploy_cutoff_slots: {}", config.deploy_cutoff_slots);
+    println!("  late_deploy_decay_bps: {}", config.late_deploy_decay_bps);
+    println!("  unbonding_period: {} sec", config.unbonding_period);
+    println!("  stake_sol_share_bps: {}", config.stake_sol_share_bps);
+    println!("  stake_boost_max_bps: {}", config.stake_boost_max_bps);
+    println!(
+        "  stake_boost_half_balance: {} LUXITE",
+        amount_to_ui_amount(config.stake_boost_half_balance, TOKEN_DECIMALS)
+    );
+    println!("  proposal_count: {}", config.proposal_count);
+    println!("  admin_veto_until: {}", config.admin_veto_until);
     Ok(())
 }
 
@@ -117,6 +200,14 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  total_staked: {} LUXITE",
         amount_to_ui_amount(treasury.total_staked, TOKEN_DECIMALS)
     );
+    println!(
+        "  total_weighted_stake: {} LUXITE",
+        amount_to_ui_amount(treasury.total_weighted_stake, TOKEN_DECIMALS)
+    );
+    println!(
+        "  stake_sol_balance: {} SOL",
+        lamports_to_sol(treasury.stake_sol_balance)
+    );
     println!(
         "  total_burned: {} LUXITE",
         amount_to_ui_amount(treasury.total_burned, TOKEN_DECIMALS)
@@ -149,9 +240,12 @@ async fn log_dimension(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let id = u64::from_str(&id).expect("Invalid ID");
     let dimension_address = dimension_pda(id).0;
     let dimension = get_dimension(rpc, id).await?;
+    let drill = get_drill(rpc, id).await?;
 
     // Calculate hit chance from richness (roll must be > richness to hit)
     let hit_chance = 100.0 - (dimension.richness as f64 / 10_000_000.0);
+    let effective_richness = dimension.richness_at(drill.depth);
+    let effective_hit_chance = 100.0 - (effective_richness as f64 / 10_000_000.0);
 
     println!("Dimension");
     println!("  address: {}", dimension_address);
@@ -162,7 +256,101 @@ async fn log_dimension(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  richness: {} ({:.2}% hit chance)",
         dimension.richness, hit_chance
     );
+    println!(
+        "  effective_richness: {} ({:.2}% hit chance at depth {})",
+        effective_richness, effective_hit_chance, drill.depth
+    );
+    println!("  depth_offset: {}", dimension.depth_offset);
     println!("  scanned_at: {}", dimension.scanned_at);
+    println!("  refined_at: {}", dimension.refined_at);
+    println!("  reroll_slot: {}", dimension.reroll_slot);
+    Ok(())
+}
+
+async fn log_dimensions(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let pages = config.dimension_count.div_ceil(DIMENSION_INDEX_PAGE_SIZE as u64);
+
+    println!("Dimensions ({})", config.dimension_count);
+    for page in 0..pages {
+        let dimension_index = get_dimension_index(rpc, page).await?;
+        for id in dimension_index.ids() {
+            println!("  {}", id);
+        }
+    }
+    Ok(())
+}
+
+async fn log_season(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let grid = get_grid(rpc).await?;
+    let id = std::env::var("ID").ok().filter(|id| !id.is_empty());
+    let id = match id {
+        Some(id) => u64::from_str(&id).expect("Invalid ID"),
+        None => grid.epoch_id,
+    };
+    let season_address = season_pda(id).0;
+    let season = get_season(rpc, id).await?;
+
+    println!("Season");
+    println!("  address: {}", season_address);
+    println!("  id: {}", season.id);
+    println!(
+        "  metric: {}",
+        if season.metric == SEASON_METRIC_MINED { "luxite mined" } else { "sol deployed" }
+    );
+    println!(
+        "  prize_pool: {} LUXITE",
+        amount_to_ui_amount(season.prize_pool, TOKEN_DECIMALS)
+    );
+    println!("  ticks: {}..{}", season.start_tick(), season.start_tick() + EPOCH_DURATION_TICKS);
+    println!("  settle_tick: {}", season.settle_tick());
+    println!("  settled: {}", season.is_settled());
+    println!("  Leaderboard");
+    for (position, entry) in season.leaderboard.iter().take(season.winners()).enumerate() {
+        let score = if season.metric == SEASON_METRIC_MINED {
+            format!("{} LUXITE", amount_to_ui_amount(entry.score, TOKEN_DECIMALS))
+        } else {
+            format!("{} SOL", lamports_to_sol(entry.score))
+        };
+        println!(
+            "    #{} {} - {} (prize {} LUXITE)",
+            position + 1,
+            entry.authority,
+            score,
+            amount_to_ui_amount(season.prize(position), TOKEN_DECIMALS)
+        );
+    }
+    Ok(())
+}
+
+async fn log_proposal(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let id = std::env::var("PROPOSAL").expect("Missing PROPOSAL env var");
+    let id = u64::from_str(&id).expect("Invalid PROPOSAL");
+    let proposal_address = proposal_pda(id).0;
+    let proposal = get_proposal(rpc, id).await?;
+    let clock = get_clock(rpc).await?;
+
+    println!("Proposal");
+    println!("  address: {}", proposal_address);
+    println!("  id: {}", proposal.id);
+    println!("  proposer: {}", proposal.proposer);
+    println!("  param: {}", proposal.param);
+    println!("  value: {}", proposal.value);
+    println!("  voting_ends_at: {}", proposal.voting_ends_at);
+    println!("  executable_at: {}", proposal.executable_at);
+    println!(
+        "  votes_for: {} LUXITE",
+        amount_to_ui_amount(proposal.votes_for, TOKEN_DECIMALS)
+    );
+    println!(
+        "  votes_against: {} LUXITE",
+        amount_to_ui_amount(proposal.votes_against, TOKEN_DECIMALS)
+    );
+    println!("  quorum: {} LUXITE", amount_to_ui_amount(proposal.quorum, TOKEN_DECIMALS));
+    println!("  voting: {}", proposal.is_voting(&clock));
+    println!("  passed: {}", proposal.passed());
+    println!("  executed: {}", proposal.executed > 0);
+    println!("  vetoed: {}", proposal.vetoed > 0);
     Ok(())
 }
 
@@ -184,6 +372,21 @@ async fn log_drill(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  lifetime_rewards_luxite: {} LUXITE",
         amount_to_ui_amount(drill.lifetime_rewards_luxite, TOKEN_DECIMALS)
     );
+    println!("  --- Hit History ---");
+    println!("  hits: {}", drill.hits);
+    println!("  misses: {}", drill.misses);
+    println!(
+        "  hit_rate: {:.2}% (lifetime)",
+        drill.hit_rate_bps() as f64 / 100.0
+    );
+    println!(
+        "  recent_hit_rate: {:.2}% (last {} excavations)",
+        drill.recent_hit_rate_bps() as f64 / 100.0,
+        drill.history_len()
+    );
+    println!("  streak: {}", drill.streak);
+    println!("  longest_hit_streak: {}", drill.longest_hit_streak);
+    println!("  longest_miss_streak: {}", drill.longest_miss_streak);
     Ok(())
 }
 
@@ -224,6 +427,20 @@ async fn log_excavation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
                 "  luxite_distributed: {} LUXITE",
                 amount_to_ui_amount(excavation.luxite_distributed, TOKEN_DECIMALS)
             );
+            if is_processed && !excavation.hit() {
+                if excavation.rebates_in_luxite() {
+                    println!(
+                        "  miss_rebate: {} LUXITE",
+                        amount_to_ui_amount(excavation.rebate_luxite, TOKEN_DECIMALS)
+                    );
+                } else {
+                    println!(
+                        "  miss_rebate: {} SOL ({} bps)",
+                        lamports_to_sol(excavation.miss_rebate(excavation.total_deployed)),
+                        excavation.miss_rebate_bps
+                    );
+                }
+            }
             println!("  expires_at: {} (expired: {})", excavation.expires_at, is_expired);
             println!("  rent_payer: {}", excavation.rent_payer);
 
@@ -262,11 +479,21 @@ async fn log_miner(
     println!("  address: {}", miner_address);
     println!("  authority: {}", miner.authority);
     println!("  dimension_id: {}", miner.dimension_id);
+    println!("  claim_delegate: {}", miner.claim_delegate);
+    println!("  recipient: {}", miner.claim_recipient());
     println!("  --- Current Excavation ---");
     println!("  excavation_id: {}", miner.excavation_id);
     println!("  checkpoint_id: {}", miner.checkpoint_id);
     println!("  needs_checkpoint: {}", needs_checkpoint);
     println!("  deployed: {} SOL", lamports_to_sol(miner.deployed));
+    println!(
+        "  weighted_deployed: {} SOL",
+        lamports_to_sol(miner.weighted_deployed)
+    );
+    println!("  weight: {}", miner.weight);
+    if miner.min_total > 0 {
+        println!("  min_total: {} SOL (conditional)", lamports_to_sol(miner.min_total));
+    }
     println!(
         "  checkpoint_fee: {} SOL",
         lamports_to_sol(miner.checkpoint_fee)
@@ -346,6 +573,32 @@ async fn log_automation(
     Ok(())
 }
 
+async fn log_session(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let key = std::env::var("KEY").expect("Missing KEY env var");
+    let key = Pubkey::from_str(&key).expect("Invalid KEY");
+
+    let session_address = session_key_pda(authority, key).0;
+    let session = get_session_key(rpc, authority, key).await?;
+    let clock = get_clock(rpc).await?;
+
+    println!("Session");
+    println!("  address: {}", session_address);
+    println!("  authority: {}", session.authority);
+    println!("  key: {}", session.key);
+    println!("  deploy: {}", session.allows(SESSION_PERMISSION_DEPLOY));
+    println!("  checkpoint: {}", session.allows(SESSION_PERMISSION_CHECKPOINT));
+    println!("  expires_at: {}", session.expires_at);
+    println!("  expired: {}", session.is_expired(&clock));
+    println!("  spend_limit: {} SOL", lamports_to_sol(session.spend_limit));
+    println!("  spent: {} SOL", lamports_to_sol(session.spent));
+    Ok(())
+}
+
 async fn log_navigator(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -357,6 +610,8 @@ async fn log_navigator(
     println!("Navigator");
     println!("  address: {}", navigator_address);
     println!("  authority: {}", navigator.authority);
+    println!("  open_miners: {}", navigator.open_miners);
+    println!("  tracks_miners: {}", navigator.tracks_miners > 0);
     println!(
         "  lifetime_dimensions_discovered: {}",
         navigator.lifetime_dimensions_discovered
@@ -373,6 +628,51 @@ async fn log_navigator(
         "  lifetime_rewards_sol: {} SOL",
         lamports_to_sol(navigator.lifetime_rewards_sol)
     );
+    println!("  Rank");
+    println!("    xp: {}", navigator.xp);
+    println!("    rank: {}", navigator.rank);
+    println!("    deploy_fee_bps: {}", navigator.deploy_fee_bps());
+    println!("    lifetime_hits: {}", navigator.lifetime_hits);
+    println!(
+        "    deploy_streak: {} (last tick {})",
+        navigator.deploy_streak, navigator.last_deploy_tick
+    );
+    println!("  Season {}", navigator.season_id);
+    println!(
+        "    season_deployed: {} SOL",
+        lamports_to_sol(navigator.season_deployed)
+    );
+    println!(
+        "    season_mined: {} LUXITE",
+        amount_to_ui_amount(navigator.season_mined, TOKEN_DECIMALS)
+    );
+    println!(
+        "    previous_season_deployed: {} SOL",
+        lamports_to_sol(navigator.previous_season_deployed)
+    );
+    println!(
+        "    previous_season_mined: {} LUXITE",
+        amount_to_ui_amount(navigator.previous_season_mined, TOKEN_DECIMALS)
+    );
+    println!("  Badges");
+    println!("    first_hit: {}", navigator.has_badge(BADGE_FIRST_HIT));
+    println!("    rich_discovery: {}", navigator.has_badge(BADGE_RICH_DISCOVERY));
+    println!("    deploy_streak: {}", navigator.has_badge(BADGE_DEPLOY_STREAK));
+    println!("  Referrals");
+    if navigator.has_referrer() {
+        println!("    referrer: {}", navigator.referrer);
+    } else {
+        println!("    referrer: none");
+    }
+    println!("    referral_count: {}", navigator.referral_count);
+    println!(
+        "    referral_rewards_sol: {} SOL",
+        lamports_to_sol(navigator.referral_rewards_sol)
+    );
+    println!(
+        "    lifetime_referral_rewards_sol: {} SOL",
+        lamports_to_sol(navigator.lifetime_referral_rewards_sol)
+    );
     Ok(())
 }
 
@@ -382,11 +682,15 @@ async fn log_stake(
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
-    let stake_address = stake_pda(authority).0;
-    let stake = get_stake(rpc, authority).await?;
+    let id = get_stake_id();
+    let stake_address = stake_pda(authority, id).0;
+    let stake = get_stake(rpc, authority, id).await?;
     println!("Stake");
     println!("  address: {}", stake_address);
     println!("  authority: {}", stake.authority);
+    println!("  id: {}", stake.id);
+    println!("  claim_delegate: {}", stake.claim_delegate);
+    println!("  recipient: {}", stake.claim_recipient());
     println!(
         "  balance: {} LUXITE",
         amount_to_ui_amount(stake.balance, TOKEN_DECIMALS)
@@ -403,9 +707,279 @@ async fn log_stake(
         "  compound_fee_reserve: {} SOL",
         lamports_to_sol(stake.compound_fee_reserve)
     );
+    println!(
+        "  weighted_balance: {} LUXITE ({} bps multiplier)",
+        amount_to_ui_amount(stake.weighted_balance, TOKEN_DECIMALS),
+        stake.multiplier_bps
+    );
+    println!("  unlock_at: {}", stake.unlock_at);
+    println!("  vote_locked_until: {}", stake.vote_locked_until);
+    println!(
+        "  unbonding_balance: {} LUXITE",
+        amount_to_ui_amount(stake.unbonding_balance, TOKEN_DECIMALS)
+    );
+    println!("  unbonding_at: {}", stake.unbonding_at);
     println!("  last_deposit_at: {}", stake.last_deposit_at);
     println!("  last_withdraw_at: {}", stake.last_withdraw_at);
     println!("  last_claim_at: {}", stake.last_claim_at);
+    println!("  sol_rewards: {} SOL", lamports_to_sol(stake.sol_rewards));
+    println!(
+        "  lifetime_sol_rewards: {} SOL",
+        lamports_to_sol(stake.lifetime_sol_rewards)
+    );
+    Ok(())
+}
+
+async fn log_stakes(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let stakes = get_stakes(rpc, authority).await?;
+    println!("Stakes");
+    println!("  authority: {}", authority);
+    println!("  positions: {}", stakes.len());
+    for (address, stake) in stakes {
+        println!("  [{}] {}", stake.id, address);
+        println!(
+            "    balance: {} LUXITE",
+            amount_to_ui_amount(stake.balance, TOKEN_DECIMALS)
+        );
+        println!(
+            "    rewards: {} LUXITE",
+            amount_to_ui_amount(stake.rewards, TOKEN_DECIMALS)
+        );
+        println!("    multiplier_bps: {}", stake.multiplier_bps);
+        println!("    sol_rewards: {} SOL", lamports_to_sol(stake.sol_rewards));
+        println!("    unlock_at: {}", stake.unlock_at);
+        println!(
+            "    unbonding_balance: {} LUXITE",
+            amount_to_ui_amount(stake.unbonding_balance, TOKEN_DECIMALS)
+        );
+    }
+    Ok(())
+}
+
+async fn log_vault(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let vault_address = vault_pda().0;
+    let vault = get_vault(rpc).await?;
+    let stake_account = rpc.get_account(&vault.stake).await?;
+    let stake = Stake::try_from_bytes(&stake_account.data)?;
+    let supply = u64::from_str(&rpc.get_token_supply(&vault.mint).await?.amount)?;
+    let receipt_address = get_associated_token_address(&payer.pubkey(), &vault.mint);
+    let receipt = rpc
+        .get_token_account_balance(&receipt_address)
+        .await
+        .map(|balance| u64::from_str(&balance.amount).unwrap_or(0))
+        .unwrap_or(0);
+    println!("Vault");
+    println!("  address: {}", vault_address);
+    println!("  mint: {}", vault.mint);
+    println!("  stake: {}", vault.stake);
+    println!(
+        "  assets: {} LUXITE",
+        amount_to_ui_amount(stake.balance, TOKEN_DECIMALS)
+    );
+    println!(
+        "  pending_rewards: {} LUXITE",
+        amount_to_ui_amount(stake.rewards, TOKEN_DECIMALS)
+    );
+    println!(
+        "  supply: {} stLUXITE",
+        amount_to_ui_amount(supply, TOKEN_DECIMALS)
+    );
+    println!(
+        "  exchange_rate: {} LUXITE/stLUXITE",
+        if supply == 0 { 1.0 } else { stake.balance as f64 / supply as f64 }
+    );
+    println!(
+        "  total_deposited: {} LUXITE",
+        amount_to_ui_amount(vault.total_deposited, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_redeemed: {} LUXITE",
+        amount_to_ui_amount(vault.total_redeemed, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_fees: {} LUXITE",
+        amount_to_ui_amount(vault.total_fees, TOKEN_DECIMALS)
+    );
+    println!(
+        "  receipt_balance: {} stLUXITE",
+        amount_to_ui_amount(receipt, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
+async fn log_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let pool_address = get_pool_address(payer);
+    let pool = get_pool(rpc, pool_address).await?;
+    println!("Pool");
+    println!("  address: {}", pool_address);
+    println!("  authority: {}", pool.authority);
+    println!("  id: {}", pool.id);
+    println!("  dimension_id: {}", pool.dimension_id);
+    println!("  balance: {} SOL", lamports_to_sol(pool.balance));
+    println!("  total_shares: {}", pool.total_shares);
+    println!("  deployed: {} SOL", lamports_to_sol(pool.deployed));
+    println!("  excavation_id: {}", pool.excavation_id);
+    println!("  max_deploy: {} SOL", lamports_to_sol(pool.max_deploy()));
+    println!("  rewards_sol: {} SOL", lamports_to_sol(pool.rewards_sol));
+    println!("  lifetime_deployed: {} SOL", lamports_to_sol(pool.lifetime_deployed));
+    println!(
+        "  lifetime_rewards_sol: {} SOL",
+        lamports_to_sol(pool.lifetime_rewards_sol)
+    );
+    println!(
+        "  lifetime_rewards_luxite: {} LUXITE",
+        amount_to_ui_amount(pool.lifetime_rewards_luxite, TOKEN_DECIMALS)
+    );
+    println!("  created_at: {}", pool.created_at);
+    println!("  harvested_at: {}", pool.harvested_at);
+    if let Ok(member) = get_pool_member(rpc, pool_address, payer.pubkey()).await {
+        println!("Pool Member");
+        println!("  shares: {}", member.shares);
+        println!("  value: {} SOL", lamports_to_sol(pool.value_of(member.shares)));
+        println!("  rewards_sol: {} SOL", lamports_to_sol(member.rewards_sol));
+        println!(
+            "  rewards_luxite: {} LUXITE",
+            amount_to_ui_amount(member.rewards_luxite, TOKEN_DECIMALS)
+        );
+        println!(
+            "  lifetime_rewards_sol: {} SOL",
+            lamports_to_sol(member.lifetime_rewards_sol)
+        );
+        println!(
+            "  lifetime_rewards_luxite: {} LUXITE",
+            amount_to_ui_amount(member.lifetime_rewards_luxite, TOKEN_DECIMALS)
+        );
+        println!("  last_deposit_at: {}", member.last_deposit_at);
+    }
+    Ok(())
+}
+
+async fn log_miner_rewards(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let stake_id = std::env::var("STAKE_ID")
+        .ok()
+        .filter(|id| !id.is_empty())
+        .map(|id| u64::from_str(&id).expect("Invalid STAKE_ID"));
+
+    let miner = get_miner(rpc, id, authority).await?;
+    let ix = localuniverse_api::sdk::view_miner_rewards(authority, id, miner.excavation_id, stake_id);
+    let data = simulate_transaction(rpc, payer, &[ix]).await?;
+    let view = MinerRewardsView::from_bytes(&data).ok_or(anyhow::anyhow!("Invalid return data"))?;
+
+    let status = match view.status {
+        CHECKPOINT_STATUS_PENDING => "PENDING",
+        CHECKPOINT_STATUS_READY => "READY",
+        CHECKPOINT_STATUS_FORFEITED => "FORFEITED",
+        _ => "NONE",
+    };
+
+    println!("Miner Rewards");
+    println!("  dimension_id: {}", view.dimension_id);
+    println!("  excavation_id: {}", view.excavation_id);
+    println!("  checkpoint: {}", status);
+    println!("  checkpoint_sol: {} SOL", lamports_to_sol(view.checkpoint_sol));
+    println!(
+        "  checkpoint_luxite: {} LUXITE",
+        amount_to_ui_amount(view.checkpoint_luxite, TOKEN_DECIMALS)
+    );
+    println!("  rewards_sol: {} SOL", lamports_to_sol(view.rewards_sol));
+    println!(
+        "  rewards_luxite: {} LUXITE",
+        amount_to_ui_amount(view.rewards_luxite, TOKEN_DECIMALS)
+    );
+    println!(
+        "  refined_luxite: {} LUXITE",
+        amount_to_ui_amount(view.refined_luxite, TOKEN_DECIMALS)
+    );
+    println!(
+        "  claimable_luxite: {} LUXITE (after claim fee)",
+        amount_to_ui_amount(view.claimable_luxite, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
+async fn log_stake_yield(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let id = get_stake_id();
+
+    let ix = localuniverse_api::sdk::view_stake_yield(authority, id);
+    let data = simulate_transaction(rpc, payer, &[ix]).await?;
+    let view = StakeYieldView::from_bytes(&data).ok_or(anyhow::anyhow!("Invalid return data"))?;
+
+    println!("Stake Yield");
+    println!("  id: {}", view.id);
+    println!(
+        "  balance: {} LUXITE",
+        amount_to_ui_amount(view.balance, TOKEN_DECIMALS)
+    );
+    println!(
+        "  weighted_balance: {} LUXITE",
+        amount_to_ui_amount(view.weighted_balance, TOKEN_DECIMALS)
+    );
+    println!(
+        "  rewards: {} LUXITE",
+        amount_to_ui_amount(view.rewards, TOKEN_DECIMALS)
+    );
+    println!("  sol_rewards: {} SOL", lamports_to_sol(view.sol_rewards));
+    println!(
+        "  unbonding_balance: {} LUXITE",
+        amount_to_ui_amount(view.unbonding_balance, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
+async fn log_excavation_odds(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let dimension_id = std::env::var("ID").expect("Missing ID env var");
+    let dimension_id = u64::from_str(&dimension_id).expect("Invalid ID");
+
+    // Defaults to the current tick
+    let tick_id = match std::env::var("TICK").ok().filter(|tick| !tick.is_empty()) {
+        Some(tick_id) => u64::from_str(&tick_id).expect("Invalid TICK"),
+        None => get_grid(rpc).await?.tick_id,
+    };
+
+    let ix = localuniverse_api::sdk::view_excavation(dimension_id, tick_id);
+    let data = simulate_transaction(rpc, payer, &[ix]).await?;
+    let view = ExcavationView::from_bytes(&data).ok_or(anyhow::anyhow!("Invalid return data"))?;
+
+    println!("Excavation Odds");
+    println!("  dimension_id: {}", view.dimension_id);
+    println!("  tick_id: {}", view.tick_id);
+    println!("  total_deployed: {} SOL", lamports_to_sol(view.total_deployed));
+    println!("  richness: {}", view.richness);
+    println!("  effective_richness: {}", view.effective_richness);
+    println!(
+        "  hit_probability: {:.2}%",
+        view.hit_probability_bps as f64 / 100.0
+    );
+    if view.processed == 1 {
+        println!("  roll: {}", view.roll);
+        println!("  did_hit: {}", view.did_hit == 1);
+    }
     Ok(())
 }
 
@@ -414,6 +988,8 @@ async fn keys() -> Result<(), anyhow::Error> {
     println!("  config: {}", config_pda().0);
     println!("  grid: {}", grid_pda().0);
     println!("  treasury: {}", treasury_pda().0);
+    println!("  vault: {}", vault_pda().0);
+    println!("  vault_mint: {}", vault_mint_pda().0);
     println!("  mint: {}", MINT_ADDRESS);
     Ok(())
 }
@@ -432,15 +1008,61 @@ async fn initialize(
     Ok(())
 }
 
+async fn initialize_vault(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = localuniverse_api::sdk::initialize_vault(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Initialized vault! Receipt mint: {}", vault_mint_pda().0);
+    Ok(())
+}
+
 async fn scan(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let referrer = get_referrer(rpc, payer.pubkey()).await;
+
+    // Manual ID if provided (admin only), otherwise the next sequential ID is assigned
+    let id = std::env::var("ID").ok().filter(|id| !id.is_empty());
+    let (id, ix) = match id {
+        Some(id) => {
+            let id = u64::from_str(&id).expect("Invalid ID");
+            let ix = localuniverse_api::sdk::scan(payer.pubkey(), id, config.dimension_count, referrer);
+            (id, ix)
+        }
+        None => {
+            let id = config.next_dimension_id;
+            let ix = localuniverse_api::sdk::scan_auto(payer.pubkey(), id, config.dimension_count, referrer);
+            (id, ix)
+        }
+    };
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Scanned dimension {}!", id);
+    Ok(())
+}
+
+async fn refine(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let id = std::env::var("ID").expect("Missing ID env var");
     let id = u64::from_str(&id).expect("Invalid ID");
-    let ix = localuniverse_api::sdk::scan(payer.pubkey(), id);
+    let reroll = std::env::var("REROLL").unwrap_or("0".to_string());
+    let reroll = reroll == "1" || reroll.to_lowercase() == "true";
+    let grid = get_grid(rpc).await?;
+    let pending = get_dimension(rpc, id).await?.reroll_slot > 0;
+    let ix = localuniverse_api::sdk::refine(payer.pubkey(), id, reroll);
     submit_transaction(rpc, payer, &[ix]).await?;
-    println!("Scanned dimension {}!", id);
+    if reroll && pending {
+        println!("Applied re-roll of dimension {}!", id);
+    } else if reroll {
+        println!("Committed re-roll of dimension {} to slot {}!", id, grid.end_slot);
+    } else {
+        println!("Refined dimension {}!", id);
+    }
     Ok(())
 }
 
@@ -453,6 +1075,13 @@ async fn deploy(
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
 
+    // Optional: refund if the excavation ends below this total (conditional deploy)
+    let min_total = std::env::var("MIN_TOTAL")
+        .ok()
+        .filter(|min_total| !min_total.is_empty())
+        .unwrap_or("0".to_string());
+    let min_total = u64::from_str(&min_total).expect("Invalid MIN_TOTAL");
+
     // Optional: deploy on behalf of another authority (automation mode)
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
@@ -460,11 +1089,27 @@ async fn deploy(
     // Get current tick from grid
     let grid = get_grid(rpc).await?;
 
-    // Calculate fee for display
-    let fee = amount * DEPLOY_FEE_BPS / DENOMINATOR_BPS;
+    // Calculate fee for display (discounted by navigator rank)
+    let fee_bps = get_navigator(rpc, authority)
+        .await
+        .map(|navigator| navigator.deploy_fee_bps())
+        .unwrap_or(DEPLOY_FEE_BPS);
+    let fee = amount * fee_bps / DENOMINATOR_BPS;
     let amount_at_risk = amount - fee;
 
-    let ix = localuniverse_api::sdk::deploy(payer.pubkey(), authority, id, grid.tick_id, amount);
+    let referrer = get_referrer(rpc, authority).await;
+    let session = authority != payer.pubkey()
+        && get_session_key(rpc, authority, payer.pubkey()).await.is_ok();
+    let ix = localuniverse_api::sdk::deploy(
+        payer.pubkey(),
+        authority,
+        id,
+        grid.tick_id,
+        amount,
+        min_total,
+        referrer,
+        session,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
 
     if authority == payer.pubkey() {
@@ -476,6 +1121,20 @@ async fn deploy(
             lamports_to_sol(fee),
             lamports_to_sol(amount_at_risk)
         );
+        if min_total > 0 {
+            println!(
+                "Refunded at checkpoint if the excavation ends below {} SOL.",
+                lamports_to_sol(min_total)
+            );
+        }
+    } else if session {
+        println!(
+            "Session deployed {} SOL to dimension {} tick {} for {}!",
+            lamports_to_sol(amount),
+            id,
+            grid.tick_id,
+            authority
+        );
     } else {
         println!(
             "Automation deployed {} SOL to dimension {} tick {} for {}!",
@@ -529,7 +1188,27 @@ async fn checkpoint(
     // Get miner's excavation_id
     let miner = get_miner(rpc, id, authority).await?;
 
-    let ix = localuniverse_api::sdk::checkpoint(payer.pubkey(), authority, id, miner.excavation_id);
+    // Optional stake position for a mining boost (defaults to the largest)
+    let stake_id = match std::env::var("STAKE_ID").ok().filter(|id| !id.is_empty()) {
+        Some(stake_id) => Some(u64::from_str(&stake_id).expect("Invalid STAKE_ID")),
+        None => get_stakes(rpc, authority)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .max_by_key(|(_, stake)| stake.balance)
+            .map(|(_, stake)| stake.id),
+    };
+
+    let session = authority != payer.pubkey()
+        && get_session_key(rpc, authority, payer.pubkey()).await.is_ok();
+    let ix = localuniverse_api::sdk::checkpoint(
+        payer.pubkey(),
+        authority,
+        id,
+        miner.excavation_id,
+        stake_id,
+        session,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
 
     if authority == payer.pubkey() {
@@ -565,15 +1244,51 @@ async fn close(
     Ok(())
 }
 
-async fn claim_luxite(
+async fn close_many(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let id = std::env::var("ID").expect("Missing ID env var");
-    let id = u64::from_str(&id).expect("Invalid ID");
-    let ix = localuniverse_api::sdk::claim_luxite(payer.pubkey(), id);
-    submit_transaction(rpc, payer, &[ix]).await?;
-    println!("Claimed LUXITE from dimension {}!", id);
+    let clock = get_clock(rpc).await?;
+    let grid = get_grid(rpc).await?;
+
+    // Find every excavation that can be closed
+    let excavations = get_excavations(rpc)
+        .await?
+        .into_iter()
+        .filter(|excavation| excavation.is_closeable(&clock, grid.tick_id))
+        .map(|excavation| (excavation.dimension_id, excavation.id, excavation.rent_payer))
+        .collect::<Vec<_>>();
+
+    // Keep each transaction within the account limit
+    for excavations in excavations.chunks(10) {
+        let ix = localuniverse_api::sdk::close_many(payer.pubkey(), excavations);
+        submit_transaction(rpc, payer, &[ix]).await?;
+        println!(
+            "Closed {} excavations for {} SOL in bounties!",
+            excavations.len(),
+            lamports_to_sol(CLOSE_BOUNTY * excavations.len() as u64)
+        );
+    }
+    Ok(())
+}
+
+async fn claim_luxite(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let miner = get_miner(rpc, id, authority).await?;
+    let ix = localuniverse_api::sdk::claim_luxite(
+        payer.pubkey(),
+        authority,
+        id,
+        miner.claim_recipient(),
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Claimed LUXITE from dimension {}!", id);
     Ok(())
 }
 
@@ -581,14 +1296,114 @@ async fn claim_sol(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
     let id = std::env::var("ID").expect("Missing ID env var");
     let id = u64::from_str(&id).expect("Invalid ID");
-    let ix = localuniverse_api::sdk::claim_sol(payer.pubkey(), id);
+    let miner = get_miner(rpc, id, authority).await?;
+    let ix = localuniverse_api::sdk::claim_sol(
+        payer.pubkey(),
+        authority,
+        id,
+        miner.claim_recipient(),
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
     println!("Claimed SOL from dimension {}!", id);
     Ok(())
 }
 
+async fn claim_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+
+    // Optional: wallet the rewards are paid to (defaults to the authority)
+    let recipient = std::env::var("RECIPIENT")
+        .ok()
+        .filter(|recipient| !recipient.is_empty())
+        .map(|recipient| Pubkey::from_str(&recipient).expect("Invalid RECIPIENT"))
+        .unwrap_or(authority);
+
+    // Optional: stake position to claim yield and SOL revenue from
+    let stake_id = std::env::var("STAKE_ID")
+        .ok()
+        .filter(|id| !id.is_empty())
+        .map(|stake_id| u64::from_str(&stake_id).expect("Invalid STAKE_ID"));
+
+    // Miners with pending rewards that pay out to the recipient
+    let dimension_ids = get_miners(rpc, authority)
+        .await?
+        .into_iter()
+        .filter(|(_, miner)| miner.claim_recipient() == recipient)
+        .filter(|(_, miner)| {
+            miner.rewards_sol > 0 || miner.rewards_luxite > 0 || miner.refined_luxite > 0
+        })
+        .map(|(_, miner)| miner.dimension_id)
+        .collect::<Vec<_>>();
+
+    // Keep each transaction within the account limit
+    let mut stake_id = stake_id;
+    for (batch, dimension_ids) in dimension_ids.chunks(20).enumerate() {
+        let ix = localuniverse_api::sdk::claim_all(
+            payer.pubkey(),
+            authority,
+            dimension_ids,
+            stake_id.take(),
+            recipient,
+        );
+        submit_transaction(rpc, payer, &[ix]).await?;
+        println!("Claimed batch {} ({} miners)!", batch, dimension_ids.len());
+    }
+    if let Some(stake_id) = stake_id {
+        let ix = localuniverse_api::sdk::claim_all(
+            payer.pubkey(),
+            authority,
+            &[],
+            Some(stake_id),
+            recipient,
+        );
+        submit_transaction(rpc, payer, &[ix]).await?;
+        println!("Claimed stake {}!", stake_id);
+    }
+    Ok(())
+}
+
+async fn close_miner(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let ix = localuniverse_api::sdk::close_miner(payer.pubkey(), id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Closed miner on dimension {}!", id);
+    Ok(())
+}
+
+async fn set_miner_delegate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let delegate = std::env::var("DELEGATE")
+        .ok()
+        .filter(|delegate| !delegate.is_empty())
+        .map(|delegate| Pubkey::from_str(&delegate).expect("Invalid DELEGATE"))
+        .unwrap_or_default();
+    let recipient = std::env::var("RECIPIENT")
+        .ok()
+        .filter(|recipient| !recipient.is_empty())
+        .map(|recipient| Pubkey::from_str(&recipient).expect("Invalid RECIPIENT"))
+        .unwrap_or_default();
+    let ix = localuniverse_api::sdk::set_miner_delegate(payer.pubkey(), id, delegate, recipient);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set claim delegate {} and recipient {} on dimension {}!", delegate, recipient, id);
+    Ok(())
+}
+
 async fn deposit(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -597,9 +1412,36 @@ async fn deposit(
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
     let compound_fee = std::env::var("COMPOUND_FEE").unwrap_or("0".to_string());
     let compound_fee = u64::from_str(&compound_fee).expect("Invalid COMPOUND_FEE");
-    let ix = localuniverse_api::sdk::deposit(payer.pubkey(), payer.pubkey(), amount, compound_fee);
+
+    // Optional lock duration in days (0, 30, 90 or 365)
+    let lock_days = std::env::var("LOCK_DAYS")
+        .ok()
+        .filter(|days| !days.is_empty())
+        .unwrap_or("0".to_string());
+    let lock_days = i64::from_str(&lock_days).expect("Invalid LOCK_DAYS");
+    let lock = STAKE_LOCK_DURATIONS
+        .iter()
+        .position(|duration| *duration == lock_days * ONE_DAY)
+        .expect("Invalid LOCK_DAYS (expected 0, 30, 90 or 365)") as u64;
+
+    let id = get_stake_id();
+    let ix = localuniverse_api::sdk::deposit(
+        payer.pubkey(),
+        payer.pubkey(),
+        id,
+        amount,
+        compound_fee,
+        lock,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
-    println!("Deposited {} LUXITE!", amount);
+    if lock > 0 {
+        println!(
+            "Deposited {} LUXITE locked for {} days ({} bps multiplier)!",
+            amount, lock_days, STAKE_LOCK_MULTIPLIERS_BPS[lock as usize]
+        );
+    } else {
+        println!("Deposited {} LUXITE!", amount);
+    }
     Ok(())
 }
 
@@ -609,9 +1451,30 @@ async fn withdraw(
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let ix = localuniverse_api::sdk::withdraw(payer.pubkey(), amount);
+    let id = get_stake_id();
+    let config = get_config(rpc).await?;
+    let ix = localuniverse_api::sdk::withdraw(payer.pubkey(), id, amount);
     submit_transaction(rpc, payer, &[ix]).await?;
-    println!("Withdrew {} LUXITE!", amount);
+    println!(
+        "Unbonding {} LUXITE! Complete the withdrawal in {} days.",
+        amount,
+        config.unbonding_period as f64 / ONE_DAY as f64
+    );
+    Ok(())
+}
+
+async fn complete_withdraw(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = get_stake_id();
+    let stake = get_stake(rpc, payer.pubkey(), id).await?;
+    let ix = localuniverse_api::sdk::complete_withdraw(payer.pubkey(), id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Withdrew {} LUXITE!",
+        amount_to_ui_amount(stake.unbonding_balance, TOKEN_DECIMALS)
+    );
     Ok(())
 }
 
@@ -621,12 +1484,239 @@ async fn claim_yield(
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let ix = localuniverse_api::sdk::claim_yield(payer.pubkey(), amount);
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let id = get_stake_id();
+    let stake = get_stake(rpc, authority, id).await?;
+    let ix = localuniverse_api::sdk::claim_yield(
+        payer.pubkey(),
+        authority,
+        id,
+        amount,
+        stake.claim_recipient(),
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
     println!("Claimed {} LUXITE yield!", amount);
     Ok(())
 }
 
+async fn claim_stake_sol(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let id = get_stake_id();
+    let stake = get_stake(rpc, authority, id).await?;
+    let ix = localuniverse_api::sdk::claim_stake_sol(
+        payer.pubkey(),
+        authority,
+        id,
+        stake.claim_recipient(),
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Claimed stake SOL rewards!");
+    Ok(())
+}
+
+async fn set_stake_delegate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = get_stake_id();
+    let delegate = std::env::var("DELEGATE")
+        .ok()
+        .filter(|delegate| !delegate.is_empty())
+        .map(|delegate| Pubkey::from_str(&delegate).expect("Invalid DELEGATE"))
+        .unwrap_or_default();
+    let recipient = std::env::var("RECIPIENT")
+        .ok()
+        .filter(|recipient| !recipient.is_empty())
+        .map(|recipient| Pubkey::from_str(&recipient).expect("Invalid RECIPIENT"))
+        .unwrap_or_default();
+    let ix = localuniverse_api::sdk::set_stake_delegate(payer.pubkey(), id, delegate, recipient);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set claim delegate {} and recipient {} on stake {}!", delegate, recipient, id);
+    Ok(())
+}
+
+async fn poke_stake(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let id = get_stake_id();
+    let ix = localuniverse_api::sdk::poke_stake(payer.pubkey(), authority, id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Dropped expired lock multiplier on stake {}!", id);
+    Ok(())
+}
+
+async fn vault_deposit(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let ix = localuniverse_api::sdk::vault_deposit(payer.pubkey(), amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Deposited {} LUXITE into the vault!", amount);
+    Ok(())
+}
+
+async fn vault_redeem(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let shares = std::env::var("SHARES").expect("Missing SHARES env var");
+    let shares = u64::from_str(&shares).expect("Invalid SHARES");
+    let ix = localuniverse_api::sdk::vault_redeem(payer.pubkey(), shares);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Redeemed {} stLUXITE from the vault, now unbonding!", shares);
+    Ok(())
+}
+
+async fn complete_redeem(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = localuniverse_api::sdk::complete_redeem(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Completed vault redemption!");
+    Ok(())
+}
+
+async fn create_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let pool_id = get_pool_id();
+    let ix = localuniverse_api::sdk::create_pool(payer.pubkey(), pool_id, id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Created pool {} ({}) on dimension {}!",
+        pool_id,
+        pool_pda(payer.pubkey(), pool_id).0,
+        id
+    );
+    Ok(())
+}
+
+async fn set_pool_dimension(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let pool_id = get_pool_id();
+    let ix = localuniverse_api::sdk::set_pool_dimension(payer.pubkey(), pool_id, id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Pool {} now deploys to dimension {}!", pool_id, id);
+    Ok(())
+}
+
+async fn pool_deposit(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let pool_address = get_pool_address(payer);
+    let ix = localuniverse_api::sdk::pool_deposit(payer.pubkey(), pool_address, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Deposited {} SOL into pool {}!", lamports_to_sol(amount), pool_address);
+    Ok(())
+}
+
+async fn pool_withdraw(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let shares = std::env::var("SHARES").expect("Missing SHARES env var");
+    let shares = u64::from_str(&shares).expect("Invalid SHARES");
+    let pool_address = get_pool_address(payer);
+    let ix = localuniverse_api::sdk::pool_withdraw(payer.pubkey(), pool_address, shares);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Redeemed {} shares from pool {}!", shares, pool_address);
+    Ok(())
+}
+
+async fn pool_deploy(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let pool_address = pool_pda(payer.pubkey(), get_pool_id()).0;
+    let pool = get_pool(rpc, pool_address).await?;
+    let grid = get_grid(rpc).await?;
+    let ix = localuniverse_api::sdk::deploy(
+        payer.pubkey(),
+        pool_address,
+        pool.dimension_id,
+        grid.tick_id,
+        amount,
+        0,
+        None,
+        false,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Pool deployed {} SOL to dimension {} excavation {}!",
+        lamports_to_sol(amount),
+        pool.dimension_id,
+        grid.tick_id
+    );
+    Ok(())
+}
+
+async fn pool_harvest(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let pool_address = get_pool_address(payer);
+    let pool = get_pool(rpc, pool_address).await?;
+    let miner = get_miner(rpc, pool.dimension_id, pool_address).await?;
+
+    // Checkpoint the pool's miner first if needed
+    let mut ixs = vec![];
+    if miner.checkpoint_id != miner.excavation_id {
+        ixs.push(localuniverse_api::sdk::checkpoint(
+            payer.pubkey(),
+            pool_address,
+            pool.dimension_id,
+            miner.excavation_id,
+            None,
+            false,
+        ));
+    }
+    ixs.push(localuniverse_api::sdk::pool_harvest(
+        payer.pubkey(),
+        pool_address,
+        pool.dimension_id,
+    ));
+    submit_transaction(rpc, payer, &ixs).await?;
+    println!(
+        "Harvested pool {} excavation {}!",
+        pool_address, miner.excavation_id
+    );
+    Ok(())
+}
+
+async fn pool_claim(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let pool_address = get_pool_address(payer);
+    let ix = localuniverse_api::sdk::pool_claim(payer.pubkey(), pool_address);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Claimed rewards from pool {}!", pool_address);
+    Ok(())
+}
+
 async fn fund_treasury(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -651,6 +1741,324 @@ async fn set_scan_fee(
     Ok(())
 }
 
+async fn set_referrer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let referrer = std::env::var("REFERRER").expect("Missing REFERRER env var");
+    let referrer = Pubkey::from_str(&referrer).expect("Invalid REFERRER");
+    let ix = localuniverse_api::sdk::set_referrer(payer.pubkey(), referrer);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set referrer to {}!", referrer);
+    Ok(())
+}
+
+async fn claim_referral(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let navigator = get_navigator(rpc, payer.pubkey()).await?;
+    let ix = localuniverse_api::sdk::claim_referral(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Claimed {} SOL in referral rewards!",
+        lamports_to_sol(navigator.referral_rewards_sol)
+    );
+    Ok(())
+}
+
+async fn close_navigator(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = localuniverse_api::sdk::close_navigator(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Closed navigator!");
+    Ok(())
+}
+
+async fn set_referral_fee(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let fee_bps = std::env::var("FEE_BPS").expect("Missing FEE_BPS env var");
+    let fee_bps = u64::from_str(&fee_bps).expect("Invalid FEE_BPS");
+    let ix = localuniverse_api::sdk::set_referral_fee(payer.pubkey(), fee_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set referral fee to {} bps!", fee_bps);
+    Ok(())
+}
+
+async fn set_miss_rebate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let rebate_bps = std::env::var("REBATE_BPS").expect("Missing REBATE_BPS env var");
+    let rebate_bps = u64::from_str(&rebate_bps).expect("Invalid REBATE_BPS");
+    let luxite_rate = std::env::var("LUXITE_RATE")
+        .ok()
+        .filter(|rate| !rate.is_empty())
+        .unwrap_or("0".to_string());
+    let luxite_rate = u64::from_str(&luxite_rate).expect("Invalid LUXITE_RATE");
+    let ix = localuniverse_api::sdk::set_miss_rebate(payer.pubkey(), rebate_bps, luxite_rate);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    if luxite_rate > 0 {
+        println!(
+            "Set miss rebate to {} bps, paid at {} LUXITE/SOL!",
+            rebate_bps,
+            amount_to_ui_amount(luxite_rate, TOKEN_DECIMALS)
+        );
+    } else {
+        println!("Set miss rebate to {} bps, paid in SOL!", rebate_bps);
+    }
+    Ok(())
+}
+
+async fn set_deploy_caps(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let env_or_zero = |key: &str| {
+        let value = std::env::var(key)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .unwrap_or("0".to_string());
+        u64::from_str(&value).unwrap_or_else(|_| panic!("Invalid {}", key))
+    };
+    let max_miner_deploy = env_or_zero("MAX_MINER");
+    let max_excavation_deploy = env_or_zero("MAX_EXCAVATION");
+    let curve = std::env::var("CURVE").unwrap_or_default();
+    let luxite_curve = match curve.as_str() {
+        "" | "linear" => LUXITE_CURVE_LINEAR,
+        "sqrt" => LUXITE_CURVE_SQRT,
+        _ => panic!("Invalid CURVE (expected linear or sqrt)"),
+    };
+    let ix = localuniverse_api::sdk::set_deploy_caps(
+        payer.pubkey(),
+        max_miner_deploy,
+        max_excavation_deploy,
+        luxite_curve,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Set deploy caps to {} SOL per miner, {} SOL per excavation ({} curve)!",
+        lamports_to_sol(max_miner_deploy),
+        lamports_to_sol(max_excavation_deploy),
+        if luxite_curve == LUXITE_CURVE_SQRT { "sqrt" } else { "linear" }
+    );
+    Ok(())
+}
+
+async fn set_deploy_window(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let cutoff = std::env::var("CUTOFF_SLOTS").expect("Missing CUTOFF_SLOTS env var");
+    let cutoff = u64::from_str(&cutoff).expect("Invalid CUTOFF_SLOTS");
+    let decay_bps = std::env::var("DECAY_BPS").expect("Missing DECAY_BPS env var");
+    let decay_bps = u64::from_str(&decay_bps).expect("Invalid DECAY_BPS");
+    let ix = localuniverse_api::sdk::set_deploy_window(payer.pubkey(), cutoff, decay_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Set deploy cutoff to {} slots and late deploy decay to {} bps!",
+        cutoff, decay_bps
+    );
+    Ok(())
+}
+
+async fn set_unbonding_period(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let days = std::env::var("DAYS").expect("Missing DAYS env var");
+    let days = i64::from_str(&days).expect("Invalid DAYS");
+    let ix = localuniverse_api::sdk::set_unbonding_period(payer.pubkey(), days * ONE_DAY);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set unbonding period to {} days!", days);
+    Ok(())
+}
+
+async fn set_stake_sol_share(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let share_bps = std::env::var("SHARE_BPS").expect("Missing SHARE_BPS env var");
+    let share_bps = u64::from_str(&share_bps).expect("Invalid SHARE_BPS");
+    let ix = localuniverse_api::sdk::set_stake_sol_share(payer.pubkey(), share_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set stake SOL share to {} bps!", share_bps);
+    Ok(())
+}
+
+async fn set_stake_boost(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let max_bps = std::env::var("MAX_BPS").expect("Missing MAX_BPS env var");
+    let max_bps = u64::from_str(&max_bps).expect("Invalid MAX_BPS");
+    let half_balance = std::env::var("HALF_BALANCE").expect("Missing HALF_BALANCE env var");
+    let half_balance = u64::from_str(&half_balance).expect("Invalid HALF_BALANCE");
+    let ix = localuniverse_api::sdk::set_stake_boost(payer.pubkey(), max_bps, half_balance);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Set stake boost to {} bps max, half at {} LUXITE!",
+        max_bps,
+        amount_to_ui_amount(half_balance, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
+async fn open_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let metric = std::env::var("METRIC")
+        .ok()
+        .filter(|metric| !metric.is_empty())
+        .unwrap_or("deployed".to_string());
+    let metric = match metric.as_str() {
+        "deployed" => SEASON_METRIC_DEPLOYED,
+        "mined" => SEASON_METRIC_MINED,
+        _ => panic!("Invalid METRIC (expected deployed or mined)"),
+    };
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let ix = localuniverse_api::sdk::open_season(payer.pubkey(), id, metric, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Opened season {} with a {} LUXITE prize pool!",
+        id,
+        amount_to_ui_amount(amount, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
+async fn settle_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let season = get_season(rpc, id).await?;
+    let winners: Vec<Pubkey> = season
+        .leaderboard
+        .iter()
+        .take(season.winners())
+        .map(|entry| entry.authority)
+        .collect();
+
+    let ix = localuniverse_api::sdk::settle_season(payer.pubkey(), id, &winners);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Settled season {} ({} winners)!", id, winners.len());
+    Ok(())
+}
+
+async fn propose(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let param = std::env::var("PARAM").expect("Missing PARAM env var");
+    let param = match param.as_str() {
+        "scan_fee" => PARAM_SCAN_FEE,
+        "referral_fee_bps" => PARAM_REFERRAL_FEE_BPS,
+        "miss_rebate_bps" => PARAM_MISS_REBATE_BPS,
+        "miss_rebate_luxite_rate" => PARAM_MISS_REBATE_LUXITE_RATE,
+        "max_miner_deploy" => PARAM_MAX_MINER_DEPLOY,
+        "max_excavation_deploy" => PARAM_MAX_EXCAVATION_DEPLOY,
+        "luxite_curve" => PARAM_LUXITE_CURVE,
+        "deploy_cutoff_slots" => PARAM_DEPLOY_CUTOFF_SLOTS,
+        "late_deploy_decay_bps" => PARAM_LATE_DEPLOY_DECAY_BPS,
+        "unbonding_period" => PARAM_UNBONDING_PERIOD,
+        "stake_sol_share_bps" => PARAM_STAKE_SOL_SHARE_BPS,
+        "stake_boost_max_bps" => PARAM_STAKE_BOOST_MAX_BPS,
+        "stake_boost_half_balance" => PARAM_STAKE_BOOST_HALF_BALANCE,
+        _ => panic!("Invalid PARAM"),
+    };
+    let value = std::env::var("VALUE").expect("Missing VALUE env var");
+    let value = u64::from_str(&value).expect("Invalid VALUE");
+    let stake_id = get_stake_id();
+    let config = get_config(rpc).await?;
+    let proposal_id = config.proposal_count;
+    let ix = localuniverse_api::sdk::propose(payer.pubkey(), stake_id, proposal_id, param, value);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Created proposal {}!", proposal_id);
+    Ok(())
+}
+
+async fn cast_vote(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let proposal_id = std::env::var("PROPOSAL").expect("Missing PROPOSAL env var");
+    let proposal_id = u64::from_str(&proposal_id).expect("Invalid PROPOSAL");
+    let support = std::env::var("SUPPORT").expect("Missing SUPPORT env var");
+    let support = match support.as_str() {
+        "for" | "yes" | "true" => true,
+        "against" | "no" | "false" => false,
+        _ => panic!("Invalid SUPPORT (expected for or against)"),
+    };
+    let stake_id = get_stake_id();
+    let ix = localuniverse_api::sdk::cast_vote(payer.pubkey(), proposal_id, stake_id, support);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Voted {} proposal {}!", if support { "for" } else { "against" }, proposal_id);
+    Ok(())
+}
+
+async fn execute_proposal(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let proposal_id = std::env::var("PROPOSAL").expect("Missing PROPOSAL env var");
+    let proposal_id = u64::from_str(&proposal_id).expect("Invalid PROPOSAL");
+    let ix = localuniverse_api::sdk::execute_proposal(payer.pubkey(), proposal_id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Executed proposal {}!", proposal_id);
+    Ok(())
+}
+
+async fn veto_proposal(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let proposal_id = std::env::var("PROPOSAL").expect("Missing PROPOSAL env var");
+    let proposal_id = u64::from_str(&proposal_id).expect("Invalid PROPOSAL");
+    let ix = localuniverse_api::sdk::veto_proposal(payer.pubkey(), proposal_id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Vetoed proposal {}!", proposal_id);
+    Ok(())
+}
+
+async fn migrate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    // Optional: comma-separated accounts to migrate (defaults to the treasury)
+    let accounts = std::env::var("ACCOUNTS")
+        .ok()
+        .filter(|accounts| !accounts.is_empty())
+        .map(|accounts| {
+            accounts
+                .split(',')
+                .map(|account| Pubkey::from_str(account.trim()).expect("Invalid ACCOUNTS"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or(vec![treasury_pda().0]);
+
+    // The config is migrated with every batch; re-read it so dimensions land on the right page
+    for chunk in accounts.chunks(DIMENSION_INDEX_PAGE_SIZE / 2) {
+        let dimension_count = get_config(rpc)
+            .await
+            .map(|config| config.dimension_count)
+            .unwrap_or(0);
+        let ix = localuniverse_api::sdk::migrate(payer.pubkey(), dimension_count, chunk);
+        submit_transaction(rpc, payer, &[ix]).await?;
+    }
+    println!("Migrated {} accounts!", accounts.len());
+    Ok(())
+}
+
 async fn automate(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -714,6 +2122,55 @@ async fn reload_sol(
     Ok(())
 }
 
+async fn create_session(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let key = std::env::var("KEY").expect("Missing KEY env var");
+    let key = Pubkey::from_str(&key).expect("Invalid KEY");
+    let hours = std::env::var("HOURS").expect("Missing HOURS env var");
+    let hours = i64::from_str(&hours).expect("Invalid HOURS");
+    let spend_limit = std::env::var("SPEND_LIMIT").expect("Missing SPEND_LIMIT env var");
+    let spend_limit = u64::from_str(&spend_limit).expect("Invalid SPEND_LIMIT");
+    let permissions = std::env::var("PERMISSIONS")
+        .ok()
+        .filter(|permissions| !permissions.is_empty())
+        .unwrap_or("all".to_string());
+    let permissions = match permissions.as_str() {
+        "deploy" => SESSION_PERMISSION_DEPLOY,
+        "checkpoint" => SESSION_PERMISSION_CHECKPOINT,
+        "all" => SESSION_PERMISSION_DEPLOY | SESSION_PERMISSION_CHECKPOINT,
+        _ => panic!("Invalid PERMISSIONS (expected deploy, checkpoint or all)"),
+    };
+    let ix = localuniverse_api::sdk::create_session(
+        payer.pubkey(),
+        key,
+        hours * ONE_HOUR,
+        spend_limit,
+        permissions,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Created session {} for {} hours with a {} SOL spend limit!",
+        key,
+        hours,
+        lamports_to_sol(spend_limit)
+    );
+    Ok(())
+}
+
+async fn revoke_session(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let key = std::env::var("KEY").expect("Missing KEY env var");
+    let key = Pubkey::from_str(&key).expect("Invalid KEY");
+    let ix = localuniverse_api::sdk::revoke_session(payer.pubkey(), key);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Revoked session {}!", key);
+    Ok(())
+}
+
 async fn get_automation(
     rpc: &RpcClient,
     authority: Pubkey,
@@ -729,6 +2186,17 @@ async fn get_automation(
 // Helpers
 // ============================================================================
 
+async fn get_session_key(
+    rpc: &RpcClient,
+    authority: Pubkey,
+    key: Pubkey,
+) -> Result<SessionKey, anyhow::Error> {
+    let address = session_key_pda(authority, key).0;
+    let account = rpc.get_account(&address).await?;
+    let session = SessionKey::try_from_bytes(&account.data)?;
+    Ok(*session)
+}
+
 async fn get_clock(rpc: &RpcClient) -> Result<Clock, anyhow::Error> {
     let data = rpc.get_account_data(&solana_sdk::sysvar::clock::ID).await?;
     let clock = bincode::deserialize::<Clock>(&data)?;
@@ -763,6 +2231,27 @@ async fn get_dimension(rpc: &RpcClient, id: u64) -> Result<Dimension, anyhow::Er
     Ok(*dimension)
 }
 
+async fn get_dimension_index(rpc: &RpcClient, page: u64) -> Result<DimensionIndex, anyhow::Error> {
+    let address = dimension_index_pda(page).0;
+    let account = rpc.get_account(&address).await?;
+    let dimension_index = DimensionIndex::try_from_bytes(&account.data)?;
+    Ok(*dimension_index)
+}
+
+async fn get_season(rpc: &RpcClient, id: u64) -> Result<Season, anyhow::Error> {
+    let address = season_pda(id).0;
+    let account = rpc.get_account(&address).await?;
+    let season = Season::try_from_bytes(&account.data)?;
+    Ok(*season)
+}
+
+async fn get_proposal(rpc: &RpcClient, id: u64) -> Result<Proposal, anyhow::Error> {
+    let address = proposal_pda(id).0;
+    let account = rpc.get_account(&address).await?;
+    let proposal = Proposal::try_from_bytes(&account.data)?;
+    Ok(*proposal)
+}
+
 async fn get_drill(rpc: &RpcClient, id: u64) -> Result<Drill, anyhow::Error> {
     let address = drill_pda(id).0;
     let account = rpc.get_account(&address).await?;
@@ -799,13 +2288,158 @@ async fn get_navigator(rpc: &RpcClient, authority: Pubkey) -> Result<Navigator,
     Ok(*navigator)
 }
 
-async fn get_stake(rpc: &RpcClient, authority: Pubkey) -> Result<Stake, anyhow::Error> {
-    let address = stake_pda(authority).0;
+async fn get_referrer(rpc: &RpcClient, authority: Pubkey) -> Option<Pubkey> {
+    let navigator = get_navigator(rpc, authority).await.ok()?;
+    navigator.has_referrer().then_some(navigator.referrer)
+}
+
+async fn get_vault(rpc: &RpcClient) -> Result<Vault, anyhow::Error> {
+    let address = vault_pda().0;
+    let account = rpc.get_account(&address).await?;
+    let vault = Vault::try_from_bytes(&account.data)?;
+    Ok(*vault)
+}
+
+async fn get_stake(rpc: &RpcClient, authority: Pubkey, id: u64) -> Result<Stake, anyhow::Error> {
+    let address = stake_pda(authority, id).0;
     let account = rpc.get_account(&address).await?;
     let stake = Stake::try_from_bytes(&account.data)?;
     Ok(*stake)
 }
 
+async fn get_pool(rpc: &RpcClient, address: Pubkey) -> Result<Pool, anyhow::Error> {
+    let account = rpc.get_account(&address).await?;
+    let pool = Pool::try_from_bytes(&account.data)?;
+    Ok(*pool)
+}
+
+async fn get_pool_member(
+    rpc: &RpcClient,
+    pool: Pubkey,
+    authority: Pubkey,
+) -> Result<PoolMember, anyhow::Error> {
+    let address = pool_member_pda(pool, authority).0;
+    let account = rpc.get_account(&address).await?;
+    let member = PoolMember::try_from_bytes(&account.data)?;
+    Ok(*member)
+}
+
+async fn get_excavations(rpc: &RpcClient) -> Result<Vec<Excavation>, anyhow::Error> {
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &localuniverse_api::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::DataSize(
+                    8 + std::mem::size_of::<Excavation>() as u64,
+                )]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    let excavations = accounts
+        .into_iter()
+        .filter_map(|(_, account)| {
+            Excavation::try_from_bytes(&account.data)
+                .ok()
+                .copied()
+        })
+        .collect::<Vec<_>>();
+    Ok(excavations)
+}
+
+async fn get_miners(
+    rpc: &RpcClient,
+    authority: Pubkey,
+) -> Result<Vec<(Pubkey, Miner)>, anyhow::Error> {
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &localuniverse_api::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::DataSize(8 + std::mem::size_of::<Miner>() as u64),
+                    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, authority.as_ref())),
+                ]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    let mut miners = accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            Miner::try_from_bytes(&account.data)
+                .ok()
+                .map(|miner| (address, *miner))
+        })
+        .collect::<Vec<_>>();
+    miners.sort_by_key(|(_, miner)| miner.dimension_id);
+    Ok(miners)
+}
+
+async fn get_stakes(
+    rpc: &RpcClient,
+    authority: Pubkey,
+) -> Result<Vec<(Pubkey, Stake)>, anyhow::Error> {
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &localuniverse_api::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::DataSize(8 + std::mem::size_of::<Stake>() as u64),
+                    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, authority.as_ref())),
+                ]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    let mut stakes = accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            Stake::try_from_bytes(&account.data)
+                .ok()
+                .map(|stake| (address, *stake))
+        })
+        .collect::<Vec<_>>();
+    stakes.sort_by_key(|(_, stake)| stake.id);
+    Ok(stakes)
+}
+
+fn get_stake_id() -> u64 {
+    let id = std::env::var("ID")
+        .ok()
+        .filter(|id| !id.is_empty())
+        .unwrap_or("0".to_string());
+    u64::from_str(&id).expect("Invalid ID")
+}
+
+fn get_pool_id() -> u64 {
+    let id = std::env::var("POOL_ID")
+        .ok()
+        .filter(|id| !id.is_empty())
+        .unwrap_or("0".to_string());
+    u64::from_str(&id).expect("Invalid POOL_ID")
+}
+
+fn get_pool_address(payer: &solana_sdk::signer::keypair::Keypair) -> Pubkey {
+    let authority = std::env::var("AUTHORITY")
+        .ok()
+        .filter(|authority| !authority.is_empty())
+        .unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    pool_pda(authority, get_pool_id()).0
+}
+
 async fn submit_transaction(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -835,3 +2469,27 @@ async fn submit_transaction(
         }
     }
 }
+
+async fn simulate_transaction(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    instructions: &[solana_sdk::instruction::Instruction],
+) -> Result<Vec<u8>, anyhow::Error> {
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &[payer],
+        blockhash,
+    );
+
+    let result = rpc.simulate_transaction(&transaction).await?.value;
+    if let Some(err) = result.err {
+        return Err(anyhow::anyhow!("Simulation failed: {:?}", err));
+    }
+    let return_data = result
+        .return_data
+        .ok_or(anyhow::anyhow!("Missing return data"))?;
+    let data = base64::engine::general_purpose::STANDARD.decode(return_data.data.0)?;
+    Ok(data)
+}
@@ -17,7 +17,7 @@ pub fn process_automate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult
 
     let clock = Clock::get()?;
 
-    let [signer_info, automation_info, executor_info, miner_info, dimension_info, system_program] =
+    let [signer_info, automation_info, executor_info, miner_info, navigator_info, dimension_info, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -46,6 +46,14 @@ pub fn process_automate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult
             &localuniverse_api::ID,
         )?;
 
+    navigator_info
+        .is_type::<Navigator>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
     system_program.is_program(&system_program::ID)?;
 
     // Create miner if needed
@@ -74,10 +82,15 @@ pub fn process_automate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult
         miner.lifetime_rewards_sol = 0;
         miner.lifetime_rewards_luxite = 0;
         miner.lifetime_deployed = 0;
-        miner.buffer_a = 0;
-        miner.buffer_b = 0;
-        miner.buffer_c = 0;
+        miner.weight = 0;
+        miner.weighted_deployed = 0;
+        miner.min_total = 0;
+        miner.claim_delegate = Pubkey::default();
+        miner.recipient = Pubkey::default();
         miner.buffer_d = 0;
+
+        let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+        navigator.open_miners += 1;
     } else {
         miner_info
             .as_account::<Miner>(&localuniverse_api::ID)?
@@ -149,9 +149,10 @@ pub fn process_buyback(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     // Share 10% with stakers
     let mut shared_amount = 0;
-    if treasury.total_staked > 0 {
+    if treasury.total_weighted_stake > 0 {
         shared_amount = total_luxite / 10;
-        treasury.stake_rewards_factor += Numeric::from_fraction(shared_amount, treasury.total_staked);
+        treasury.stake_rewards_factor +=
+            Numeric::from_fraction(shared_amount, treasury.total_weighted_stake);
     }
 
     // Burn 90%
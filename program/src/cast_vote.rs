@@ -0,0 +1,77 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Votes on a governance proposal with a stake position's balance. Only positions last funded
+/// before the proposal was created can vote. Each position votes once, and cannot be withdrawn
+/// until the proposal can no longer be executed, so stake cannot be moved to a second position
+/// and counted again.
+pub fn process_cast_vote(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = CastVote::try_from_bytes(data)?;
+    let proposal_id = u64::from_le_bytes(args.proposal_id);
+    let stake_id = u64::from_le_bytes(args.stake_id);
+    let support = u64::from_le_bytes(args.support) > 0;
+
+    let clock = Clock::get()?;
+
+    let [signer_info, proposal_info, stake_info, vote_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let proposal = proposal_info
+        .is_writable()?
+        .has_seeds(&[PROPOSAL, &proposal_id.to_le_bytes()], &localuniverse_api::ID)?
+        .as_account_mut::<Proposal>(&localuniverse_api::ID)?;
+
+    let stake = stake_info
+        .is_writable()?
+        .has_seeds(
+            &stake_seeds(signer_info.key.as_ref(), &stake_id.to_le_bytes()),
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?;
+
+    vote_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(
+            &[VOTE, &proposal_id.to_le_bytes(), stake_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    system_program.is_program(&system_program::ID)?;
+
+    assert!(proposal.is_voting(&clock), "Voting has ended");
+    assert!(!proposal.is_closed(), "Proposal is closed");
+    assert!(stake.balance > 0, "No stake to vote with");
+    assert!(stake.last_deposit_at < proposal.created_at, "Stake deposited after proposal");
+
+    // Create vote account
+    create_program_account::<Vote>(
+        vote_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[VOTE, &proposal_id.to_le_bytes(), stake_info.key.as_ref()],
+    )?;
+
+    let vote = vote_info.as_account_mut::<Vote>(&localuniverse_api::ID)?;
+    vote.proposal_id = proposal_id;
+    vote.stake = *stake_info.key;
+    vote.authority = *signer_info.key;
+    vote.support = support as u64;
+    vote.weight = stake.balance;
+    vote.voted_at = clock.unix_timestamp;
+
+    proposal.vote(support, stake.balance);
+
+    // Lock the counted balance until the proposal expires
+    stake.vote_locked_until = stake.vote_locked_until.max(proposal.expires_at());
+
+    Ok(())
+}
@@ -6,16 +6,30 @@ use localuniverse_api::{
     state::*,
 };
 
-/// Checkpoints a miner's rewards after their excavation is processed.
+use crate::{rank::*, session::*};
+
+/// Checkpoints a miner's rewards after their excavation is processed. Pass a stake position
+/// of the miner's authority as a trailing account to boost the LUXITE share of a hit; only
+/// stake deposited before the excavation opened counts. A session
+/// key of the authority, passed before the stake, checkpoints as the authority.
 pub fn process_checkpoint(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
 
-    let [signer_info, grid_info, excavation_info, miner_info, treasury_info] = accounts else {
+    if accounts.len() < 8 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(8);
+
+    let [signer_info, config_info, grid_info, excavation_info, miner_info, navigator_info, season_info, treasury_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     signer_info.is_signer()?;
 
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
+
     let grid = grid_info
         .has_seeds(&[GRID], &localuniverse_api::ID)?
         .as_account::<Grid>(&localuniverse_api::ID)?;
@@ -42,6 +56,19 @@ pub fn process_checkpoint(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
         &localuniverse_api::ID,
     )?;
 
+    navigator_info
+        .is_type::<Navigator>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, miner.authority.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    let season_id = miner_excavation_id / EPOCH_DURATION_TICKS;
+    season_info
+        .is_writable()?
+        .has_seeds(&[SEASON, &season_id.to_le_bytes()], &localuniverse_api::ID)?;
+
     treasury_info
         .is_type::<Treasury>(&localuniverse_api::ID)?
         .is_writable()?
@@ -52,6 +79,8 @@ pub fn process_checkpoint(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
         let miner = miner_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
         miner.checkpoint_id = miner.excavation_id;
         miner.deployed = 0;
+        miner.weighted_deployed = 0;
+        miner.weight = 0;
         return Ok(());
     }
 
@@ -66,6 +95,8 @@ pub fn process_checkpoint(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
         let miner = miner_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
         miner.checkpoint_id = miner.excavation_id;
         miner.deployed = 0;
+        miner.weighted_deployed = 0;
+        miner.weight = 0;
         return Ok(());
     }
 
@@ -80,14 +111,41 @@ pub fn process_checkpoint(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
         let miner = miner_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
         miner.checkpoint_id = miner.excavation_id;
         miner.deployed = 0;
+        miner.weighted_deployed = 0;
+        miner.weight = 0;
         return Ok(());
     }
 
     let miner = miner_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
     let deployed = miner.deployed;
 
+    // Optional session key of the miner's authority
+    let (session, remaining_accounts) = session_key(
+        remaining_accounts,
+        &miner.authority,
+        signer_info.key,
+        SESSION_PERMISSION_CHECKPOINT,
+        &clock,
+    )?;
+
+    // Optional stake position of the miner's authority, counted only if it was held since
+    // before the excavation opened so it cannot be added once the outcome is known
+    let staked = match remaining_accounts.first() {
+        Some(stake_info) => {
+            let stake = stake_info
+                .as_account::<Stake>(&localuniverse_api::ID)?
+                .assert(|s| s.authority == miner.authority)?;
+            if excavation.is_staked_before(stake) {
+                stake.balance
+            } else {
+                0
+            }
+        }
+        None => 0,
+    };
+
     let mut bot_fee: u64 = 0;
-    let is_bot = *signer_info.key != miner.authority;
+    let is_bot = *signer_info.key != miner.authority && session.is_none();
     let in_bot_window = clock.slot >= excavation.expires_at.saturating_sub(TWELVE_HOURS_SLOTS);
 
     if is_bot && in_bot_window && miner.checkpoint_fee > 0 {
@@ -95,31 +153,52 @@ pub fn process_checkpoint(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
         miner.checkpoint_fee = 0;
     }
 
-    let mut rewards_sol: u64 = 0;
-    let mut rewards_luxite: u64 = 0;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    let rewards = miner.checkpoint_rewards(excavation, config, treasury.luxite_balance, staked);
 
-    if excavation.hit() && deployed > 0 {
-        rewards_sol = deployed;
+    // Return forfeited LUXITE to emissions and pay the stake boost from them
+    treasury.luxite_balance += rewards.forfeited_luxite;
+    treasury.luxite_balance -= rewards.boost;
 
-        if excavation.luxite_distributed > 0 && excavation.total_deployed > 0 {
-            rewards_luxite = ((excavation.luxite_distributed as u128 * deployed as u128)
-                / excavation.total_deployed as u128) as u64;
+    // Update navigator stats and rank
+    let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    if excavation.hit() && deployed > 0 && !rewards.refunded {
+        navigator.record_hit();
+        update_rank(navigator, &clock);
+    }
+
+    // Update season leaderboard
+    let mined = if excavation.hit() { rewards.luxite } else { 0 };
+    let recorded =
+        deployed > 0 && !rewards.refunded && navigator.record_season(season_id, deployed, mined);
+    if recorded && !season_info.data_is_empty() {
+        let season = season_info.as_account_mut::<Season>(&localuniverse_api::ID)?;
+        if !season.is_settled() {
+            season.record(navigator.authority, navigator.season_score(season.id, season.metric));
         }
     }
 
-    let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
     miner.update_rewards(treasury);
 
     miner.checkpoint_id = miner.excavation_id;
     miner.deployed = 0;
+    miner.weighted_deployed = 0;
+    miner.weight = 0;
+    miner.min_total = 0;
 
-    miner.rewards_sol += rewards_sol;
-    miner.rewards_luxite += rewards_luxite;
+    miner.rewards_sol += rewards.sol;
+    miner.rewards_luxite += rewards.luxite;
 
-    treasury.total_unclaimed += rewards_luxite;
+    treasury.total_unclaimed += rewards.luxite;
+
+    if rewards.sol > 0 {
+        excavation_info.send(rewards.sol, miner_info);
+    }
 
-    if rewards_sol > 0 {
-        excavation_info.send(rewards_sol, miner_info);
+    if rewards.treasury_sol > 0 {
+        excavation_info.send(rewards.treasury_sol, treasury_info);
+        treasury.collect_sol(rewards.treasury_sol, config.stake_sol_share_bps);
     }
 
     if bot_fee > 0 {
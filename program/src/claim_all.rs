@@ -0,0 +1,147 @@
+use steel::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Claims SOL and LUXITE from any number of an authority's miners, plus optionally one of its
+/// stake positions, in a single payout. Pass the miners and stake as trailing accounts; all of
+/// them must pay out to the same recipient and be claimable by the signer.
+pub fn process_claim_all(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    if accounts.len() < 10 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(10);
+
+    let [signer_info, navigator_info, recipient_info, mint_info, recipient_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let navigator = navigator_info
+        .is_writable()?
+        .as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    let authority = navigator.authority;
+
+    navigator_info.has_seeds(
+        &[NAVIGATOR, authority.as_ref()],
+        &localuniverse_api::ID,
+    )?;
+
+    recipient_info.is_writable()?;
+
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(treasury_info.key, mint_info.key)?;
+
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    let mut total_luxite: u64 = 0;
+    let mut total_sol: u64 = 0;
+
+    for account_info in remaining_accounts {
+        account_info.is_writable()?;
+
+        if account_info.is_type::<Miner>(&localuniverse_api::ID).is_ok() {
+            let miner = account_info
+                .as_account_mut::<Miner>(&localuniverse_api::ID)?
+                .assert_mut(|m| m.authority == authority)?
+                .assert_mut(|m| m.can_claim(signer_info.key))?
+                .assert_mut(|m| m.claim_recipient() == *recipient_info.key)?;
+            account_info.has_seeds(
+                &[MINER, &miner.dimension_id.to_le_bytes(), authority.as_ref()],
+                &localuniverse_api::ID,
+            )?;
+
+            // Claim rewards (LUXITE is charged the usual 10% refining fee)
+            let luxite = miner.claim_luxite(&clock, treasury);
+            let sol = miner.claim_sol(&clock);
+
+            navigator.lifetime_rewards_luxite += luxite;
+            navigator.lifetime_rewards_sol += sol;
+
+            if sol > 0 {
+                account_info.send(sol, recipient_info);
+            }
+
+            total_luxite += luxite;
+            total_sol += sol;
+        } else if account_info.is_type::<Stake>(&localuniverse_api::ID).is_ok() {
+            let stake = account_info
+                .as_account_mut::<Stake>(&localuniverse_api::ID)?
+                .assert_mut(|s| s.authority == authority)?
+                .assert_mut(|s| s.can_claim(signer_info.key))?
+                .assert_mut(|s| s.claim_recipient() == *recipient_info.key)?;
+            account_info.has_seeds(
+                &stake_seeds(authority.as_ref(), &stake.id.to_le_bytes()),
+                &localuniverse_api::ID,
+            )?;
+
+            // Claim all yield and SOL revenue
+            let luxite = stake.claim(u64::MAX, &clock, treasury);
+            let sol = stake.claim_sol(treasury);
+
+            if sol > 0 {
+                treasury_info.send(sol, recipient_info);
+            }
+
+            total_luxite += luxite;
+            total_sol += sol;
+        } else {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    sol_log(&format!(
+        "Claimed {} SOL and {} LUXITE",
+        lamports_to_sol(total_sol),
+        spl_token::amount_to_ui_amount(total_luxite, TOKEN_DECIMALS)
+    ));
+
+    if total_luxite == 0 {
+        return Ok(());
+    }
+
+    // Create recipient token account if needed
+    if recipient_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            recipient_info,
+            recipient_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_tokens_info.as_associated_token_account(recipient_info.key, mint_info.key)?;
+    }
+
+    // Transfer LUXITE from treasury to recipient
+    transfer_signed(
+        treasury_info,
+        treasury_tokens_info,
+        recipient_tokens_info,
+        token_program,
+        total_luxite,
+        &[TREASURY],
+    )?;
+
+    Ok(())
+}
@@ -5,15 +5,23 @@ use localuniverse_api::{
     state::*,
 };
 
-/// Claims pending LUXITE rewards for a miner.
+/// Claims pending LUXITE rewards for a miner. The authority or its claim delegate may sign;
+/// rewards go to the miner's recipient, whose token account is created at the signer's expense
+/// if needed. The recipient wallet is an optional trailing account, defaulting to the signer.
 pub fn process_claim_luxite(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
 
-    let [signer_info, miner_info, navigator_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+    if accounts.len() < 10 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(10);
+
+    let [signer_info, miner_info, navigator_info, mint_info, recipient_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    let recipient_info = remaining_accounts.first().unwrap_or(signer_info);
 
     signer_info.is_signer()?;
 
@@ -23,22 +31,25 @@ pub fn process_claim_luxite(accounts: &[AccountInfo], _data: &[u8]) -> ProgramRe
 
     let miner = miner_info.as_account::<Miner>(&localuniverse_api::ID)?;
     let dimension_id = miner.dimension_id;
+    let authority = miner.authority;
 
     miner_info.has_seeds(
-        &[MINER, &dimension_id.to_le_bytes(), signer_info.key.as_ref()],
+        &[MINER, &dimension_id.to_le_bytes(), authority.as_ref()],
         &localuniverse_api::ID,
     )?;
 
-    assert!(miner.authority == *signer_info.key, "Not authorized");
+    assert!(miner.can_claim(signer_info.key), "Not authorized");
 
     navigator_info
         .is_type::<Navigator>(&localuniverse_api::ID)?
         .is_writable()?
         .has_seeds(
-            &[NAVIGATOR, signer_info.key.as_ref()],
+            &[NAVIGATOR, authority.as_ref()],
             &localuniverse_api::ID,
         )?;
 
+    recipient_info.has_address(&miner.claim_recipient())?;
+
     mint_info.has_address(&MINT_ADDRESS)?;
 
     treasury_info
@@ -49,18 +60,18 @@ pub fn process_claim_luxite(accounts: &[AccountInfo], _data: &[u8]) -> ProgramRe
     treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
 
     // Create recipient token account if needed
-    if recipient_info.data_is_empty() {
+    if recipient_tokens_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
             signer_info,
             recipient_info,
+            recipient_tokens_info,
             mint_info,
             system_program,
             token_program,
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        recipient_tokens_info.as_associated_token_account(recipient_info.key, mint_info.key)?;
     }
 
     // Claim rewards
@@ -81,7 +92,7 @@ pub fn process_claim_luxite(accounts: &[AccountInfo], _data: &[u8]) -> ProgramRe
     transfer_signed(
         treasury_info,
         treasury_tokens_info,
-        recipient_info,
+        recipient_tokens_info,
         token_program,
         amount,
         &[TREASURY],
@@ -0,0 +1,49 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    event::*,
+};
+
+/// Claims pending SOL referral rewards for a navigator.
+pub fn process_claim_referral(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    let [signer_info, navigator_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    navigator_info
+        .is_type::<Navigator>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    assert!(navigator.authority == *signer_info.key, "Not authorized");
+
+    // Claim rewards
+    let amount = navigator.claim_referral();
+
+    if amount == 0 {
+        return Ok(());
+    }
+
+    // Transfer SOL from navigator account to signer
+    navigator_info.send(amount, signer_info);
+
+    ClaimReferralEvent {
+        disc: LocalUniverseEvent::ClaimReferral as u64,
+        authority: *signer_info.key,
+        amount,
+        ts: clock.unix_timestamp,
+    }
+    .log();
+
+    Ok(())
+}
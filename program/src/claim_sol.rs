@@ -5,13 +5,21 @@ use localuniverse_api::{
     state::*,
 };
 
-/// Claims pending SOL rewards for a miner.
+/// Claims pending SOL rewards for a miner. The authority or its claim delegate may sign;
+/// rewards go to the miner's recipient. The recipient wallet is an optional trailing account,
+/// defaulting to the signer.
 pub fn process_claim_sol(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
 
+    if accounts.len() < 3 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(3);
+
     let [signer_info, miner_info, navigator_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    let recipient_info = remaining_accounts.first().unwrap_or(signer_info);
 
     signer_info.is_signer()?;
 
@@ -21,22 +29,27 @@ pub fn process_claim_sol(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResul
 
     let miner = miner_info.as_account::<Miner>(&localuniverse_api::ID)?;
     let dimension_id = miner.dimension_id;
+    let authority = miner.authority;
 
     miner_info.has_seeds(
-        &[MINER, &dimension_id.to_le_bytes(), signer_info.key.as_ref()],
+        &[MINER, &dimension_id.to_le_bytes(), authority.as_ref()],
         &localuniverse_api::ID,
     )?;
 
-    assert!(miner.authority == *signer_info.key, "Not authorized");
+    assert!(miner.can_claim(signer_info.key), "Not authorized");
 
     navigator_info
         .is_type::<Navigator>(&localuniverse_api::ID)?
         .is_writable()?
         .has_seeds(
-            &[NAVIGATOR, signer_info.key.as_ref()],
+            &[NAVIGATOR, authority.as_ref()],
             &localuniverse_api::ID,
         )?;
 
+    recipient_info
+        .is_writable()?
+        .has_address(&miner.claim_recipient())?;
+
     // Claim rewards
     let miner = miner_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
     let amount = miner.claim_sol(&clock);
@@ -49,8 +62,8 @@ pub fn process_claim_sol(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResul
     let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
     navigator.lifetime_rewards_sol += amount;
 
-    // Transfer SOL from miner account to signer
-    miner_info.send(amount, signer_info);
+    // Transfer SOL from miner account to recipient
+    miner_info.send(amount, recipient_info);
 
     Ok(())
 }
@@ -0,0 +1,50 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Claims the SOL revenue earned by a stake position. The authority or its claim delegate may
+/// sign; rewards go to the position's recipient.
+pub fn process_claim_stake_sol(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = ClaimStakeSOL::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
+
+    let [signer_info, stake_info, treasury_info, recipient_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate stake
+    let stake = stake_info
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?
+        .assert_mut(|s| s.can_claim(signer_info.key))?;
+    stake_info.has_seeds(
+        &stake_seeds(stake.authority.as_ref(), &id.to_le_bytes()),
+        &localuniverse_api::ID,
+    )?;
+
+    // Validate treasury
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    // Validate recipient
+    recipient_info
+        .is_writable()?
+        .has_address(&stake.claim_recipient())?;
+
+    // Claim SOL rewards
+    let amount = stake.claim_sol(treasury);
+
+    // Transfer SOL from treasury to recipient
+    if amount > 0 {
+        treasury_info.send(amount, recipient_info);
+    }
+
+    Ok(())
+}
@@ -5,17 +5,26 @@ use localuniverse_api::{
     state::*,
 };
 
-/// Claims yield from the staking contract.
+/// Claims yield from the staking contract. The authority or its claim delegate may sign;
+/// yield goes to the position's recipient, whose token account is created at the signer's
+/// expense if needed. The recipient wallet is an optional trailing account, defaulting to the
+/// signer.
 pub fn process_claim_yield(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let args = ClaimYield::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
     let amount = u64::from_le_bytes(args.amount);
 
     let clock = Clock::get()?;
 
+    if accounts.len() < 9 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(9);
+
     let [
         signer_info,
         mint_info,
-        recipient_info,
+        recipient_tokens_info,
         stake_info,
         treasury_info,
         treasury_tokens_info,
@@ -25,6 +34,7 @@ pub fn process_claim_yield(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
     ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    let recipient_info = remaining_accounts.first().unwrap_or(signer_info);
 
     // Validate signer
     signer_info.is_signer()?;
@@ -32,13 +42,20 @@ pub fn process_claim_yield(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
     // Validate mint
     mint_info.has_address(&MINT_ADDRESS)?;
 
-    // Validate recipient
-    recipient_info.is_writable()?;
+    // Validate recipient tokens
+    recipient_tokens_info.is_writable()?;
 
     // Validate stake
     let stake = stake_info
         .as_account_mut::<Stake>(&localuniverse_api::ID)?
-        .assert_mut(|s| s.authority == *signer_info.key)?;
+        .assert_mut(|s| s.can_claim(signer_info.key))?;
+    stake_info.has_seeds(
+        &stake_seeds(stake.authority.as_ref(), &id.to_le_bytes()),
+        &localuniverse_api::ID,
+    )?;
+
+    // Validate recipient
+    recipient_info.has_address(&stake.claim_recipient())?;
 
     // Validate treasury
     let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
@@ -54,18 +71,18 @@ pub fn process_claim_yield(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
     // Create recipient token account if needed
-    if recipient_info.data_is_empty() {
+    if recipient_tokens_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
             signer_info,
             recipient_info,
+            recipient_tokens_info,
             mint_info,
             system_program,
             token_program,
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        recipient_tokens_info.as_associated_token_account(recipient_info.key, mint_info.key)?;
     }
 
     // Claim yield from stake account
@@ -75,7 +92,7 @@ pub fn process_claim_yield(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
     transfer_signed(
         treasury_info,
         treasury_tokens_info,
-        recipient_info,
+        recipient_tokens_info,
         token_program,
         amount,
         &[TREASURY],
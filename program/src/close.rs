@@ -10,12 +10,16 @@ use localuniverse_api::{
 pub fn process_close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
 
-    let [signer_info, grid_info, excavation_info, rent_payer_info, treasury_info] = accounts else {
+    let [signer_info, config_info, grid_info, excavation_info, rent_payer_info, treasury_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     signer_info.is_signer()?;
 
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
+
     let grid = grid_info
         .has_seeds(&[GRID], &localuniverse_api::ID)?
         .as_account::<Grid>(&localuniverse_api::ID)?;
@@ -30,9 +34,7 @@ pub fn process_close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     assert!(excavation.rent_payer == *rent_payer_info.key, "Wrong rent payer");
 
     // Can close if expired OR stale (unprocessed and old)
-    let is_expired = clock.slot >= excavation.expires_at;
-    let is_stale = !excavation.is_processed() && excavation.id + 1 < grid.tick_id;
-    assert!(is_expired || is_stale, "Excavation not expired or stale");
+    assert!(excavation.is_closeable(&clock, grid.tick_id), "Excavation not expired or stale");
 
     let dimension_id = excavation.dimension_id;
     let excavation_id = excavation.id;
@@ -56,7 +58,7 @@ pub fn process_close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     if unclaimed_sol > 0 {
         excavation_info.send(unclaimed_sol, treasury_info);
         let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
-        treasury.sol_balance += unclaimed_sol;
+        treasury.collect_sol(unclaimed_sol, config.stake_sol_share_bps);
     }
 
     excavation_info.close(rent_payer_info)?;
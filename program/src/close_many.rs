@@ -0,0 +1,91 @@
+use steel::*;
+use solana_program::{log::sol_log, rent::Rent};
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Closes any number of expired or stale excavations, passed as trailing (excavation,
+/// rent payer) pairs. Unclaimed SOL goes to the treasury and rent to each rent payer, less a
+/// fixed bounty per excavation paid to the caller. Excavations that are already closed or not
+/// yet closeable are skipped.
+pub fn process_close_many(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    if accounts.len() < 4 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(4);
+
+    let [signer_info, config_info, grid_info, treasury_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if remaining_accounts.len() % 2 != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    signer_info.is_signer()?;
+
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
+
+    let grid = grid_info
+        .has_seeds(&[GRID], &localuniverse_api::ID)?
+        .as_account::<Grid>(&localuniverse_api::ID)?;
+
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    let size = 8 + std::mem::size_of::<Excavation>();
+    let min_rent = Rent::get()?.minimum_balance(size);
+
+    let mut closed: u64 = 0;
+    for pair in remaining_accounts.chunks(2) {
+        let [excavation_info, rent_payer_info] = pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Skip excavations already closed (e.g. by another sweeper)
+        if excavation_info.data_is_empty() {
+            continue;
+        }
+
+        let excavation = excavation_info
+            .is_writable()?
+            .as_account::<Excavation>(&localuniverse_api::ID)?
+            .assert(|e| e.rent_payer == *rent_payer_info.key)?;
+
+        excavation_info.has_seeds(
+            &[EXCAVATION, &excavation.dimension_id.to_le_bytes(), &excavation.id.to_le_bytes()],
+            &localuniverse_api::ID,
+        )?;
+
+        rent_payer_info.is_writable()?;
+
+        if !excavation.is_closeable(&clock, grid.tick_id) {
+            continue;
+        }
+
+        // Pay the bounty, from unclaimed SOL first and then rent
+        let bounty = CLOSE_BOUNTY.min(excavation_info.lamports());
+        excavation_info.send(bounty, signer_info);
+
+        let unclaimed_sol = excavation_info.lamports().saturating_sub(min_rent);
+        if unclaimed_sol > 0 {
+            excavation_info.send(unclaimed_sol, treasury_info);
+            treasury.collect_sol(unclaimed_sol, config.stake_sol_share_bps);
+        }
+
+        excavation_info.close(rent_payer_info)?;
+        closed += 1;
+    }
+
+    sol_log(&format!("Closed {} excavations", closed));
+
+    Ok(())
+}
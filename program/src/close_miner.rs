@@ -0,0 +1,49 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Closes a checkpointed miner with no pending rewards, returning its rent and leftover
+/// checkpoint fee to the authority.
+pub fn process_close_miner(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = CloseMiner::try_from_bytes(data)?;
+    let dimension_id = u64::from_le_bytes(args.dimension_id);
+
+    let [signer_info, miner_info, navigator_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate miner
+    miner_info
+        .is_writable()?
+        .has_seeds(
+            &[MINER, &dimension_id.to_le_bytes(), signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account::<Miner>(&localuniverse_api::ID)?
+        .assert(|m| m.authority == *signer_info.key)?
+        .assert(|m| m.checkpoint_id == m.excavation_id)?
+        .assert(|m| m.rewards_sol == 0)?
+        .assert(|m| m.rewards_luxite == 0 && m.refined_luxite == 0)?;
+
+    // Validate navigator
+    let navigator = navigator_info
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+
+    navigator.open_miners = navigator.open_miners.saturating_sub(1);
+
+    // Close miner
+    miner_info.close(signer_info)?;
+
+    Ok(())
+}
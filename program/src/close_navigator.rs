@@ -0,0 +1,35 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Closes a navigator with no open miners and no unclaimed referral rewards. Its stats, rank
+/// and referrer are lost. Navigators created before open miners were counted cannot be closed,
+/// as their miners cannot be proven closed.
+pub fn process_close_navigator(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [signer_info, navigator_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate navigator
+    navigator_info
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account::<Navigator>(&localuniverse_api::ID)?
+        .assert(|n| n.authority == *signer_info.key)?
+        .assert(|n| n.tracks_miners > 0)?
+        .assert(|n| n.open_miners == 0)?
+        .assert(|n| n.referral_rewards_sol == 0)?;
+
+    // Close navigator
+    navigator_info.close(signer_info)?;
+
+    Ok(())
+}
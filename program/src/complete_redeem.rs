@@ -0,0 +1,110 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    error::*,
+};
+
+/// Transfers LUXITE redeemed from the staking vault to the redeemer once the unbonding period
+/// has passed, closing the redemption account.
+pub fn process_complete_redeem(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    let [
+        signer_info,
+        mint_info,
+        recipient_info,
+        vault_info,
+        redemption_info,
+        stake_info,
+        stake_tokens_info,
+        system_program,
+        token_program,
+        associated_token_program,
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate mint
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    // Validate recipient
+    recipient_info.is_writable()?;
+
+    // Validate vault
+    let vault = vault_info
+        .has_seeds(&[VAULT], &localuniverse_api::ID)?
+        .as_account::<Vault>(&localuniverse_api::ID)?;
+
+    // Validate redemption
+    let redemption = redemption_info
+        .is_writable()?
+        .has_seeds(
+            &[VAULT_REDEMPTION, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account::<VaultRedemption>(&localuniverse_api::ID)?
+        .assert(|r| r.authority == *signer_info.key)?;
+
+    // Validate stake
+    let stake = stake_info
+        .is_writable()?
+        .has_address(&vault.stake)?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?;
+
+    // Validate stake tokens
+    stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Unbonding must have finished
+    if redemption.is_unbonding(&clock) {
+        return Err(LocalUniverseError::StakeUnbonding.into());
+    }
+
+    // Create recipient token account if needed
+    if recipient_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            signer_info,
+            recipient_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+    }
+
+    // Release the redemption from the vault position's unbonding balance
+    let amount = redemption.amount;
+    stake.unbonding_balance -= amount;
+
+    // Transfer LUXITE to recipient
+    transfer_signed(
+        stake_info,
+        stake_tokens_info,
+        recipient_info,
+        token_program,
+        amount,
+        &stake_seeds(vault_info.key.as_ref(), &VAULT_STAKE_ID.to_le_bytes()),
+    )?;
+
+    // Close redemption account
+    redemption_info.close(signer_info)?;
+
+    // Safety check
+    let stake_tokens = stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    assert!(stake_tokens.amount() >= stake.balance + stake.unbonding_balance);
+
+    Ok(())
+}
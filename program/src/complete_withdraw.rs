@@ -0,0 +1,93 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Transfers unbonded LUXITE out of the staking contract once the unbonding period has passed.
+pub fn process_complete_withdraw(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = CompleteWithdraw::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
+
+    let clock = Clock::get()?;
+
+    let [
+        signer_info,
+        mint_info,
+        recipient_info,
+        stake_info,
+        stake_tokens_info,
+        system_program,
+        token_program,
+        associated_token_program,
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate mint
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    // Validate recipient
+    recipient_info.is_writable()?;
+
+    // Validate stake
+    let stake = stake_info
+        .has_seeds(
+            &stake_seeds(signer_info.key.as_ref(), &id.to_le_bytes()),
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+
+    // Validate stake tokens
+    stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Unbonding must have finished
+    if stake.is_unbonding(&clock) {
+        return Err(LocalUniverseError::StakeUnbonding.into());
+    }
+
+    // Create recipient token account if needed
+    if recipient_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            signer_info,
+            recipient_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+    }
+
+    // Release the unbonded balance
+    let amount = stake.complete_withdraw();
+
+    // Transfer LUXITE to recipient
+    transfer_signed(
+        stake_info,
+        stake_tokens_info,
+        recipient_info,
+        token_program,
+        amount,
+        &stake_seeds(&stake.authority.to_bytes(), &stake.id.to_le_bytes()),
+    )?;
+
+    // Safety check
+    let stake_tokens = stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    assert!(stake_tokens.amount() >= stake.balance + stake.unbonding_balance);
+
+    Ok(())
+}
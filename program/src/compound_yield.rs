@@ -6,7 +6,9 @@ use localuniverse_api::{
 };
 
 /// Compounds yield from the staking contract. Called by bots.
-pub fn process_compound_yield(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+pub fn process_compound_yield(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = CompoundYield::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
     let clock = Clock::get()?;
 
     let [
@@ -31,6 +33,7 @@ pub fn process_compound_yield(accounts: &[AccountInfo], _data: &[u8]) -> Program
     // Validate stake - must have fee reserve and not claimed recently
     let stake = stake_info
         .as_account_mut::<Stake>(&localuniverse_api::ID)?
+        .assert_mut(|s| s.id == id)?
         .assert_mut(|s| s.compound_fee_reserve >= COMPOUND_FEE_PER_TRANSACTION)?
         .assert_mut(|s| s.last_claim_at + ONE_DAY < clock.unix_timestamp)?;
 
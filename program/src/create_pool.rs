@@ -0,0 +1,105 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Creates a mining pool operated by the signer, along with the navigator its miners use.
+pub fn process_create_pool(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = CreatePool::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
+    let dimension_id = u64::from_le_bytes(args.dimension_id);
+
+    let clock = Clock::get()?;
+
+    let [signer_info, pool_info, navigator_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate pool
+    pool_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(
+            &[POOL, signer_info.key.as_ref(), &id.to_le_bytes()],
+            &localuniverse_api::ID,
+        )?;
+
+    // Validate navigator
+    navigator_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, pool_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+
+    // Create pool account
+    create_program_account::<Pool>(
+        pool_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[POOL, signer_info.key.as_ref(), &id.to_le_bytes()],
+    )?;
+
+    let pool = pool_info.as_account_mut::<Pool>(&localuniverse_api::ID)?;
+    pool.authority = *signer_info.key;
+    pool.id = id;
+    pool.dimension_id = dimension_id;
+    pool.balance = 0;
+    pool.total_shares = 0;
+    pool.deployed = 0;
+    pool.excavation_id = 0;
+    pool.sol_rewards_factor = Numeric::ZERO;
+    pool.luxite_rewards_factor = Numeric::ZERO;
+    pool.rewards_sol = 0;
+    pool.lifetime_deployed = 0;
+    pool.lifetime_rewards_sol = 0;
+    pool.lifetime_rewards_luxite = 0;
+    pool.created_at = clock.unix_timestamp;
+    pool.harvested_at = 0;
+
+    // Create the pool's navigator
+    create_program_account::<Navigator>(
+        navigator_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[NAVIGATOR, pool_info.key.as_ref()],
+    )?;
+
+    let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    navigator.authority = *pool_info.key;
+    navigator.lifetime_dimensions_discovered = 0;
+    navigator.lifetime_rewards_luxite = 0;
+    navigator.lifetime_rewards_sol = 0;
+    navigator.lifetime_deployed = 0;
+    navigator.created_at = clock.unix_timestamp;
+    navigator.referrer = Pubkey::default();
+    navigator.referral_rewards_sol = 0;
+    navigator.lifetime_referral_rewards_sol = 0;
+    navigator.referral_count = 0;
+    navigator.xp = 0;
+    navigator.rank = 0;
+    navigator.badges = 0;
+    navigator.deploy_streak = 0;
+    navigator.last_deploy_tick = 0;
+    navigator.lifetime_hits = 0;
+    navigator.season_id = 0;
+    navigator.season_deployed = 0;
+    navigator.season_mined = 0;
+    navigator.open_miners = 0;
+    navigator.tracks_miners = 1;
+    navigator.previous_season_deployed = 0;
+    navigator.previous_season_mined = 0;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Creates a session key that can deploy and/or checkpoint for the signer until it expires.
+/// The spend limit is escrowed in the session account and returned on revoke.
+pub fn process_create_session(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = CreateSession::try_from_bytes(data)?;
+    let key = Pubkey::new_from_array(args.key);
+    let duration = i64::from_le_bytes(args.duration);
+    let spend_limit = u64::from_le_bytes(args.spend_limit);
+    let permissions = u64::from_le_bytes(args.permissions);
+
+    let clock = Clock::get()?;
+
+    let [signer_info, session_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate session
+    session_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(
+            &[SESSION, signer_info.key.as_ref(), key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+
+    assert!(key != *signer_info.key, "Session key must differ from authority");
+    assert!(duration > 0 && duration <= MAX_SESSION_DURATION, "Invalid duration");
+    assert!(
+        permissions != 0
+            && permissions & !(SESSION_PERMISSION_DEPLOY | SESSION_PERMISSION_CHECKPOINT) == 0,
+        "Invalid permissions"
+    );
+
+    // Create session account
+    create_program_account::<SessionKey>(
+        session_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[SESSION, signer_info.key.as_ref(), key.as_ref()],
+    )?;
+
+    let session = session_info.as_account_mut::<SessionKey>(&localuniverse_api::ID)?;
+    session.authority = *signer_info.key;
+    session.key = key;
+    session.permissions = permissions;
+    session.expires_at = clock.unix_timestamp + duration;
+    session.spend_limit = spend_limit;
+    session.spent = 0;
+    session.created_at = clock.unix_timestamp;
+
+    // Escrow the spend limit
+    if spend_limit > 0 {
+        session_info.collect(spend_limit, signer_info)?;
+    }
+
+    Ok(())
+}
@@ -3,19 +3,31 @@ use solana_program::{log::sol_log, native_token::lamports_to_sol};
 
 use localuniverse_api::{
     consts::*,
+    error::*,
     instruction::Deploy,
     state::*,
+    event::*,
 };
 
+use crate::{rank::*, referral::*, session::*};
+
 /// Deploys SOL to a dimension's excavation. Takes 1% fee, rest is at risk.
-/// Can be called directly by user, or by executor on behalf of automation.
+/// Can be called directly by user, by executor on behalf of automation, by a session key
+/// passed as the first trailing account (before the referrer), or by a pool's operator with
+/// the pool as authority.
 pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let args = Deploy::try_from_bytes(data)?;
     let mut amount = u64::from_le_bytes(args.amount);
+    let min_total = u64::from_le_bytes(args.min_total);
 
     let clock = Clock::get()?;
 
-    let [signer_info, authority_info, automation_info, grid_info, dimension_info, drill_info, excavation_info, miner_info, navigator_info, treasury_info, system_program] =
+    if accounts.len() < 12 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(12);
+
+    let [signer_info, authority_info, automation_info, config_info, grid_info, dimension_info, drill_info, excavation_info, miner_info, navigator_info, treasury_info, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -24,11 +36,22 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     signer_info.is_signer()?;
     authority_info.is_writable()?;
 
+    config_info
+        .is_type::<Config>(&localuniverse_api::ID)?
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?;
+
+    let config = config_info.as_account::<Config>(&localuniverse_api::ID)?;
+
     let grid = grid_info
         .has_seeds(&[GRID], &localuniverse_api::ID)?
         .as_account::<Grid>(&localuniverse_api::ID)?
         .assert(|g| clock.slot >= g.start_slot && clock.slot < g.end_slot)?;
 
+    // Reject deploys in the cutoff window before the tick ends
+    if clock.slot + config.deploy_cutoff_slots >= grid.end_slot {
+        return Err(LocalUniverseError::DeployWindowClosed.into());
+    }
+
     let dimension = dimension_info
         .as_account::<Dimension>(&localuniverse_api::ID)?;
     let dimension_id = dimension.id;
@@ -72,8 +95,35 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     system_program.is_program(&system_program::ID)?;
 
+    // Check if authority is a pool deployed by its operator
+    let pool = if authority_info.is_type::<Pool>(&localuniverse_api::ID).is_ok() {
+        let pool = authority_info.as_account::<Pool>(&localuniverse_api::ID)?;
+        authority_info.has_seeds(
+            &[POOL, pool.authority.as_ref(), &pool.id.to_le_bytes()],
+            &localuniverse_api::ID,
+        )?;
+        assert!(pool.authority == *signer_info.key, "Signer must be pool operator");
+        assert!(pool.dimension_id == dimension_id, "Pool dimension mismatch");
+        Some(authority_info)
+    } else {
+        None
+    };
+
+    // Check if signer is a session key of the authority
+    let (session, remaining_accounts) = if pool.is_none() && *signer_info.key != *authority_info.key {
+        session_key(
+            remaining_accounts,
+            authority_info.key,
+            signer_info.key,
+            SESSION_PERMISSION_DEPLOY,
+            &clock,
+        )?
+    } else {
+        (None, remaining_accounts)
+    };
+
     // Check if signer is automation executor
-    let automation = if !automation_info.data_is_empty() {
+    let automation = if session.is_none() && pool.is_none() && !automation_info.data_is_empty() {
         automation_info
             .is_writable()?
             .has_seeds(
@@ -92,20 +142,35 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         Some(automation_info)
     } else {
         assert!(
-            *signer_info.key == *authority_info.key,
+            *signer_info.key == *authority_info.key || session.is_some() || pool.is_some(),
             "Signer must be authority when no automation"
         );
         None
     };
 
-    // Calculate 1% fee
+    // Calculate deploy fee (1%, discounted by navigator rank)
+    let fee_bps = navigator_info
+        .as_account::<Navigator>(&localuniverse_api::ID)?
+        .deploy_fee_bps();
     let fee = amount
-        .checked_mul(DEPLOY_FEE_BPS)
+        .checked_mul(fee_bps)
         .unwrap()
         .checked_div(DENOMINATOR_BPS)
         .unwrap();
     let amount_after_fee = amount.checked_sub(fee).unwrap();
 
+    // Split referral share from the fee
+    let referrer_info = referrer_navigator(
+        navigator_info.as_account::<Navigator>(&localuniverse_api::ID)?,
+        remaining_accounts,
+    )?;
+    let referral_fee = if referrer_info.is_some() {
+        referral_share(fee, config)
+    } else {
+        0
+    };
+    let protocol_fee = fee - referral_fee;
+
     // Create excavation account if first deploy this tick
     if excavation_info.data_is_empty() {
         create_program_account::<Excavation>(
@@ -126,10 +191,13 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         excavation.total_miners = 0;
         excavation.did_hit = 0;
         excavation.luxite_distributed = 0;
-        excavation.buffer_a = 0;
-        excavation.buffer_b = 0;
-        excavation.buffer_c = 0;
-        excavation.buffer_d = 0;
+        excavation.miss_rebate_bps = 0;
+        excavation.miss_rebate_luxite_rate = 0;
+        excavation.rebate_luxite = 0;
+        excavation.total_weight = 0;
+        excavation.luxite_curve = config.luxite_curve;
+        excavation.conditional_deployed = 0;
+        excavation.opened_at = clock.unix_timestamp;
     }
 
     // Create miner account if new
@@ -158,16 +226,21 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         miner.lifetime_rewards_sol = 0;
         miner.lifetime_rewards_luxite = 0;
         miner.lifetime_deployed = 0;
-        miner.buffer_a = 0;
-        miner.buffer_b = 0;
-        miner.buffer_c = 0;
+        miner.weight = 0;
+        miner.weighted_deployed = 0;
+        miner.min_total = 0;
+        miner.claim_delegate = Pubkey::default();
+        miner.recipient = Pubkey::default();
         miner.buffer_d = 0;
+
+        let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+        navigator.open_miners += 1;
     }
 
     let miner = miner_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
 
     // Verify miner authority
-    if automation.is_some() {
+    if automation.is_some() || session.is_some() || pool.is_some() {
         assert!(miner.authority == *authority_info.key, "Miner authority mismatch");
     } else {
         assert!(miner.authority == *signer_info.key, "Not authorized");
@@ -182,14 +255,45 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         );
 
         miner.deployed = 0;
+        miner.weighted_deployed = 0;
+        miner.weight = 0;
         miner.excavation_id = grid.tick_id;
     }
 
     // Track if this is miner's first deploy this excavation (for miner count)
     let is_first_deploy = miner.deployed == 0;
 
+    // Only the authority may choose a threshold; delegated deploys keep the authority's
+    let min_total = if *signer_info.key == *authority_info.key {
+        min_total
+    } else if is_first_deploy {
+        0
+    } else {
+        miner.min_total
+    };
+
+    // Conditional deploys must use the same threshold for the whole excavation
+    if is_first_deploy {
+        miner.min_total = min_total;
+    } else {
+        assert!(miner.min_total == min_total, "Conditional threshold mismatch");
+    }
+
+    // Enforce deploy caps
+    let excavation = excavation_info.as_account::<Excavation>(&localuniverse_api::ID)?;
+    if config.max_miner_deploy > 0 && miner.deployed + amount_after_fee > config.max_miner_deploy {
+        return Err(LocalUniverseError::DeployCapExceeded.into());
+    }
+    if config.max_excavation_deploy > 0
+        && excavation.total_deployed + amount_after_fee > config.max_excavation_deploy
+    {
+        return Err(LocalUniverseError::DeployCapExceeded.into());
+    }
+
     // Update miner totals
     miner.deployed += amount_after_fee;
+    miner.weighted_deployed +=
+        config.time_weighted(amount_after_fee, clock.slot, grid.start_slot, grid.end_slot);
     miner.lifetime_deployed += amount_after_fee;
 
     // Top up checkpoint fee if empty
@@ -206,6 +310,14 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     }
 
     excavation.total_deployed += amount_after_fee;
+    if min_total > 0 {
+        excavation.conditional_deployed += amount_after_fee;
+    }
+
+    // Update LUXITE weights
+    let weight = excavation.weight(miner.weighted_deployed);
+    excavation.total_weight = excavation.total_weight - miner.weight + weight;
+    miner.weight = weight;
 
     // Update drill
     let drill = drill_info.as_account_mut::<Drill>(&localuniverse_api::ID)?;
@@ -214,10 +326,29 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     // Update navigator
     let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
     navigator.lifetime_deployed += amount_after_fee;
+    navigator.record_deploy(grid.tick_id);
+    update_rank(navigator, &clock);
 
     // Update treasury with fee
     let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
-    treasury.sol_balance += fee;
+    treasury.collect_sol(protocol_fee, config.stake_sol_share_bps);
+
+    // Credit referrer
+    if let Some(referrer_info) = referrer_info {
+        let referrer = referrer_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+        referrer.referral_rewards_sol += referral_fee;
+        referrer.lifetime_referral_rewards_sol += referral_fee;
+
+        ReferralRewardEvent {
+            disc: LocalUniverseEvent::ReferralReward as u64,
+            referrer: referrer.authority,
+            referee: *authority_info.key,
+            amount: referral_fee,
+            source: 0,
+            ts: clock.unix_timestamp,
+        }
+        .log();
+    }
 
     // Transfer SOL
     if let Some(auto_info) = automation {
@@ -232,7 +363,10 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
         automation.balance -= total_needed;
 
-        auto_info.send(fee, treasury_info);
+        auto_info.send(protocol_fee, treasury_info);
+        if let Some(referrer_info) = referrer_info {
+            auto_info.send(referral_fee, referrer_info);
+        }
         auto_info.send(amount_after_fee, excavation_info);
         auto_info.send(automation_fee, signer_info);
 
@@ -247,12 +381,70 @@ pub fn process_deploy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
             dimension_id,
             lamports_to_sol(automation_fee)
         ));
+    } else if let Some(pool_info) = pool {
+        let pool = pool_info.as_account_mut::<Pool>(&localuniverse_api::ID)?;
+        if pool.is_deployed() {
+            assert!(
+                pool.excavation_id == grid.tick_id,
+                "Pool must harvest before deploying to a new excavation"
+            );
+        }
+        assert!(pool.can_deploy(clock.slot), "Pool must stay idle after a harvest");
+        assert!(amount <= pool.max_deploy(), "Pool deploy limit exceeded");
+
+        pool.balance -= amount;
+        pool.deployed += amount;
+        pool.excavation_id = grid.tick_id;
+        pool.lifetime_deployed += amount;
+
+        pool_info.send(protocol_fee, treasury_info);
+        if let Some(referrer_info) = referrer_info {
+            pool_info.send(referral_fee, referrer_info);
+        }
+        pool_info.send(amount_after_fee, excavation_info);
+
+        sol_log(&format!(
+            "Pool deployed {} SOL to dimension {}",
+            lamports_to_sol(amount),
+            dimension_id
+        ));
+    } else if let Some(session_info) = session {
+        session_info.is_writable()?;
+        let session = session_info.as_account_mut::<SessionKey>(&localuniverse_api::ID)?;
+        if amount > session.remaining() {
+            return Err(LocalUniverseError::SessionSpendLimit.into());
+        }
+
+        session.spent += amount;
+
+        session_info.send(protocol_fee, treasury_info);
+        if let Some(referrer_info) = referrer_info {
+            session_info.send(referral_fee, referrer_info);
+        }
+        session_info.send(amount_after_fee, excavation_info);
+
+        sol_log(&format!(
+            "Session deployed {} SOL to dimension {}",
+            lamports_to_sol(amount),
+            dimension_id
+        ));
     } else {
         solana_program::program::invoke(
-            &solana_program::system_instruction::transfer(signer_info.key, treasury_info.key, fee),
+            &solana_program::system_instruction::transfer(signer_info.key, treasury_info.key, protocol_fee),
             &[signer_info.clone(), treasury_info.clone()],
         )?;
 
+        if let Some(referrer_info) = referrer_info {
+            solana_program::program::invoke(
+                &solana_program::system_instruction::transfer(
+                    signer_info.key,
+                    referrer_info.key,
+                    referral_fee,
+                ),
+                &[signer_info.clone(), referrer_info.clone()],
+            )?;
+        }
+
         solana_program::program::invoke(
             &solana_program::system_instruction::transfer(
                 signer_info.key,
@@ -4,10 +4,14 @@ use localuniverse_api::{
     state::*,
 };
 
+/// Deposits LUXITE into a stake position, creating it if needed. The payer funds rent and the
+/// compound fee reserve, so the authority can be a PDA that holds no SOL.
 pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let args = localuniverse_api::instruction::Deposit::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
     let amount = u64::from_le_bytes(args.amount);
     let compound_fee = u64::from_le_bytes(args.compound_fee);
+    let lock = u64::from_le_bytes(args.lock) as usize;
     let clock = Clock::get()?;
 
     let [
@@ -28,6 +32,8 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     signer_info.is_signer()?;
     payer_info.is_signer()?;
 
+    assert!(lock < STAKE_LOCK_OPTIONS, "Invalid lock option");
+
     mint_info.has_address(&MINT_ADDRESS)?;
 
     let sender = sender_info
@@ -37,7 +43,7 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     stake_info
         .is_writable()?
         .has_seeds(
-            &[STAKE, signer_info.key.as_ref()],
+            &stake_seeds(signer_info.key.as_ref(), &id.to_le_bytes()),
             &localuniverse_api::ID,
         )?;
 
@@ -54,7 +60,7 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
             system_program,
             payer_info,
             &localuniverse_api::ID,
-            &[STAKE, signer_info.key.as_ref()],
+            &stake_seeds(signer_info.key.as_ref(), &id.to_le_bytes()),
         )?;
 
         let stake = stake_info.as_account_mut::<Stake>(&localuniverse_api::ID)?;
@@ -67,6 +73,18 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         stake.last_claim_at = 0;
         stake.last_deposit_at = 0;
         stake.last_withdraw_at = 0;
+        stake.unlock_at = 0;
+        stake.multiplier_bps = DENOMINATOR_BPS;
+        stake.weighted_balance = 0;
+        stake.id = id;
+        stake.unbonding_balance = 0;
+        stake.unbonding_at = 0;
+        stake.sol_rewards_factor = treasury.stake_sol_rewards_factor;
+        stake.sol_rewards = 0;
+        stake.lifetime_sol_rewards = 0;
+        stake.claim_delegate = Pubkey::default();
+        stake.recipient = Pubkey::default();
+        stake.vote_locked_until = 0;
         stake
     } else {
         stake_info
@@ -76,7 +94,7 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     if stake_tokens_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
+            payer_info,
             stake_info,
             stake_tokens_info,
             mint_info,
@@ -90,6 +108,17 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     let amount = stake.deposit(amount, &clock, treasury, &sender);
 
+    // Lock the position for a reward multiplier. Deposits into a locked position restart its
+    // lock, so new stake never earns the multiplier for less than the full duration.
+    let lock = if amount > 0 && stake.is_locked(&clock) {
+        lock.max(stake.lock_option())
+    } else {
+        lock
+    };
+    if lock > 0 {
+        stake.lock(lock, &clock, treasury);
+    }
+
     transfer(
         signer_info,
         sender_info,
@@ -100,11 +129,11 @@ pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     if compound_fee > 0 {
         stake.compound_fee_reserve += compound_fee;
-        stake_info.collect(compound_fee, signer_info)?;
+        stake_info.collect(compound_fee, payer_info)?;
     }
 
     let stake_tokens = stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    assert!(stake_tokens.amount() >= stake.balance + stake.unbonding_balance);
 
     Ok(())
 }
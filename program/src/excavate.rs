@@ -4,13 +4,17 @@ use solana_program::sysvar::slot_hashes;
 use localuniverse_api::{
     consts::*,
     state::*,
+    event::*,
 };
 
-/// Processes an excavation. Determines hit or miss based on RNG vs richness.
+use crate::refine::apply_reroll;
+
+/// Processes an excavation. Determines hit or miss based on RNG vs richness, then applies any
+/// re-roll committed by Refine whose slot has passed.
 pub fn process_excavate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
 
-    let [signer_info, grid_info, dimension_info, drill_info, excavation_info, treasury_info, slot_hashes_info] =
+    let [signer_info, config_info, grid_info, dimension_info, drill_info, excavation_info, treasury_info, slot_hashes_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -18,26 +22,34 @@ pub fn process_excavate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult
 
     signer_info.is_signer()?;
 
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
+
     let grid = grid_info
         .has_seeds(&[GRID], &localuniverse_api::ID)?
         .as_account::<Grid>(&localuniverse_api::ID)?;
 
     let dimension = dimension_info
-        .as_account::<Dimension>(&localuniverse_api::ID)?;
+        .is_writable()?
+        .as_account_mut::<Dimension>(&localuniverse_api::ID)?;
     let dimension_id = dimension.id;
-    let richness = dimension.richness;
 
     dimension_info.has_seeds(
         &[DIMENSION, &dimension_id.to_le_bytes()],
         &localuniverse_api::ID,
     )?;
 
-    drill_info
+    let drill = drill_info
         .is_writable()?
         .has_seeds(
             &[DRILL, &dimension_id.to_le_bytes()],
             &localuniverse_api::ID,
-        )?;
+        )?
+        .as_account::<Drill>(&localuniverse_api::ID)?;
+
+    // Richness depletes as the drill goes deeper
+    let richness = dimension.richness_at(drill.depth);
 
     // Process the previous tick's excavation
     let previous_tick_id = grid.tick_id.saturating_sub(1);
@@ -49,16 +61,18 @@ pub fn process_excavate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult
             &localuniverse_api::ID,
         )?;
 
+    slot_hashes_info.has_address(&slot_hashes::ID)?;
+
     // Return early if excavation doesn't exist
     if excavation_info.data_is_empty() {
-        return Ok(());
+        return apply_reroll(dimension, drill.depth, signer_info.key, slot_hashes_info, &clock);
     }
 
     let excavation = excavation_info.as_account::<Excavation>(&localuniverse_api::ID)?;
 
     // Already processed or no activity
     if excavation.slot_hash != [0; 32] || excavation.total_deployed == 0 {
-        return Ok(());
+        return apply_reroll(dimension, drill.depth, signer_info.key, slot_hashes_info, &clock);
     }
 
     let sol_deployed = excavation.total_deployed;
@@ -67,8 +81,6 @@ pub fn process_excavate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult
         .is_writable()?
         .has_seeds(&[TREASURY], &localuniverse_api::ID)?;
 
-    slot_hashes_info.has_address(&slot_hashes::ID)?;
-
     // Get slot hash for RNG
     let slot_hashes_data = slot_hashes_info.try_borrow_data()?;
     let hash_start = 8 + 8;
@@ -80,22 +92,15 @@ pub fn process_excavate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult
     let excavation = excavation_info.as_account_mut::<Excavation>(&localuniverse_api::ID)?;
     excavation.slot_hash = hash_bytes;
     excavation.expires_at = clock.slot + ONE_DAY_SLOTS;
+    excavation.miss_rebate_bps = config.miss_rebate_bps.min(MAX_MISS_REBATE_BPS);
+    excavation.miss_rebate_luxite_rate = config.miss_rebate_luxite_rate;
 
     let Some(rng) = excavation.rng() else {
-        return Ok(());
-    };
-
-    // Apply penalty if below minimum SOL threshold
-    let effective_richness = if sol_deployed >= MIN_DEPLOYED_FOR_FULL_RATE {
-        richness as u64
-    } else {
-        let scale = MIN_DEPLOYED_FOR_FULL_RATE
-            .checked_div(sol_deployed)
-            .unwrap_or(10);
-        (richness as u64).saturating_mul(scale).min(999_999_999)
+        return apply_reroll(dimension, drill.depth, signer_info.key, slot_hashes_info, &clock);
     };
 
-    // Roll against richness
+    // Roll against richness, penalized if below minimum SOL threshold
+    let effective_richness = excavation.effective_richness(richness);
     let roll = rng % 1_000_000_000;
     let did_hit = roll > effective_richness;
 
@@ -126,13 +131,46 @@ pub fn process_excavate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult
         // === MISS ===
         excavation.did_hit = 0;
 
-        // Send all SOL to treasury
-        excavation_info.send(sol_deployed, treasury_info);
-        treasury.sol_balance += sol_deployed;
+        // Keep the SOL rebate on the excavation for checkpoint claims, or reserve
+        // LUXITE from emissions if rebates are paid in LUXITE. Conditional SOL stays on
+        // the excavation until checkpoint decides whether it is refunded.
+        let conditional_sol = excavation.conditional_deployed;
+        let rebate_sol = excavation.miss_rebate(sol_deployed);
+        let kept_rebate = if excavation.rebates_in_luxite() {
+            let rebate_luxite = ((rebate_sol as u128 * excavation.miss_rebate_luxite_rate as u128)
+                / ONE_SOL as u128) as u64;
+            let rebate_luxite = rebate_luxite.min(treasury.luxite_balance);
+            excavation.rebate_luxite = rebate_luxite;
+            treasury.luxite_balance -= rebate_luxite;
+            0
+        } else {
+            excavation.miss_rebate(sol_deployed - conditional_sol)
+        };
+        let kept_sol = conditional_sol + kept_rebate;
+
+        // Send the rest of the SOL to treasury
+        let treasury_sol = sol_deployed - kept_sol;
+        excavation_info.send(treasury_sol, treasury_info);
+        treasury.collect_sol(treasury_sol, config.stake_sol_share_bps);
     }
 
-    // Update drill depth
+    // Update drill depth and hit history
     drill.depth += 1;
+    drill.record(did_hit);
+
+    ExcavateEvent {
+        disc: LocalUniverseEvent::Excavate as u64,
+        dimension_id,
+        tick_id: previous_tick_id,
+        richness: effective_richness,
+        luxite_distributed: excavation.luxite_distributed,
+        total_deployed: sol_deployed,
+        miner_count: excavation.total_miners,
+        depth: drill.depth,
+        ts: clock.unix_timestamp,
+    }
+    .log();
 
-    Ok(())
+    // The re-roll takes effect from the next excavation
+    apply_reroll(dimension, drill.depth, signer_info.key, slot_hashes_info, &clock)
 }
@@ -0,0 +1,41 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Applies a passed governance proposal to the config once its timelock has elapsed and
+/// before its execution window closes. Callable by anyone.
+pub fn process_execute_proposal(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = ExecuteProposal::try_from_bytes(data)?;
+    let proposal_id = u64::from_le_bytes(args.proposal_id);
+
+    let clock = Clock::get()?;
+
+    let [signer_info, config_info, proposal_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account_mut::<Config>(&localuniverse_api::ID)?;
+
+    let proposal = proposal_info
+        .is_writable()?
+        .has_seeds(&[PROPOSAL, &proposal_id.to_le_bytes()], &localuniverse_api::ID)?
+        .as_account_mut::<Proposal>(&localuniverse_api::ID)?;
+
+    assert!(!proposal.is_closed(), "Proposal is closed");
+    assert!(clock.unix_timestamp >= proposal.executable_at, "Timelock not elapsed");
+    assert!(!proposal.is_expired(&clock), "Proposal expired");
+    assert!(proposal.passed(), "Proposal did not pass");
+
+    assert!(config.set_param(proposal.param, proposal.value), "Invalid parameter");
+    proposal.executed = 1;
+
+    Ok(())
+}
@@ -57,6 +57,22 @@ pub fn process_initialize(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
     config.admin = *signer_info.key;
     config.fee_collector = ADMIN_FEE_COLLECTOR;
     config.scan_fee = DIMENSION_SCAN_FEE;
+    config.next_dimension_id = AUTO_DIMENSION_ID_START;
+    config.dimension_count = 0;
+    config.referral_fee_bps = REFERRAL_FEE_BPS;
+    config.miss_rebate_bps = MISS_REBATE_BPS;
+    config.miss_rebate_luxite_rate = 0;
+    config.max_miner_deploy = 0;
+    config.max_excavation_deploy = 0;
+    config.luxite_curve = LUXITE_CURVE_LINEAR;
+    config.deploy_cutoff_slots = 0;
+    config.late_deploy_decay_bps = 0;
+    config.unbonding_period = UNBONDING_PERIOD;
+    config.stake_sol_share_bps = STAKE_SOL_SHARE_BPS;
+    config.stake_boost_max_bps = STAKE_BOOST_MAX_BPS;
+    config.stake_boost_half_balance = STAKE_BOOST_HALF_BALANCE;
+    config.proposal_count = 0;
+    config.admin_veto_until = clock.unix_timestamp + GOVERNANCE_BOOTSTRAP_PERIOD;
 
     // Create grid PDA
     create_program_account::<Grid>(
@@ -89,9 +105,12 @@ pub fn process_initialize(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResu
     treasury.stake_rewards_factor = Numeric::ZERO;
     treasury.total_refined = 0;
     treasury.total_staked = 0;
+    treasury.total_weighted_stake = 0;
     treasury.total_unclaimed = 0;
     treasury.total_emitted = 0;
     treasury.total_burned = 0;
+    treasury.stake_sol_rewards_factor = Numeric::ZERO;
+    treasury.stake_sol_balance = 0;
 
     // Create treasury token account
     if treasury_tokens_info.data_is_empty() {
@@ -0,0 +1,147 @@
+use steel::*;
+use solana_program::program_pack::Pack;
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    error::*,
+};
+
+/// Creates the staking vault, its receipt mint and the stake position it holds (admin only).
+pub fn process_initialize_vault(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [
+        signer_info,
+        config_info,
+        vault_info,
+        vault_mint_info,
+        mint_info,
+        stake_info,
+        stake_tokens_info,
+        treasury_info,
+        system_program,
+        token_program,
+        associated_token_program,
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    config_info
+        .as_account::<Config>(&localuniverse_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    vault_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[VAULT], &localuniverse_api::ID)?;
+
+    vault_mint_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[VAULT_MINT], &localuniverse_api::ID)?;
+
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    stake_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(
+            &stake_seeds(vault_info.key.as_ref(), &VAULT_STAKE_ID.to_le_bytes()),
+            &localuniverse_api::ID,
+        )?;
+
+    stake_tokens_info.is_empty()?.is_writable()?;
+
+    let treasury = treasury_info
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account::<Treasury>(&localuniverse_api::ID)?;
+
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Create vault account
+    create_program_account::<Vault>(
+        vault_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[VAULT],
+    )?;
+
+    let vault = vault_info.as_account_mut::<Vault>(&localuniverse_api::ID)?;
+    vault.mint = *vault_mint_info.key;
+    vault.stake = *stake_info.key;
+    vault.total_deposited = 0;
+    vault.total_redeemed = 0;
+    vault.total_fees = 0;
+
+    // Create receipt mint with the vault as mint authority
+    allocate_account(
+        vault_mint_info,
+        system_program,
+        signer_info,
+        spl_token::state::Mint::LEN,
+        &spl_token::ID,
+        &[VAULT_MINT],
+    )?;
+
+    solana_program::program::invoke(
+        &spl_token::instruction::initialize_mint2(
+            &spl_token::ID,
+            vault_mint_info.key,
+            vault_info.key,
+            None,
+            TOKEN_DECIMALS,
+        )?,
+        &[vault_mint_info.clone(), token_program.clone()],
+    )?;
+
+    // Create the vault's stake position
+    create_program_account::<Stake>(
+        stake_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &stake_seeds(vault_info.key.as_ref(), &VAULT_STAKE_ID.to_le_bytes()),
+    )?;
+
+    let stake = stake_info.as_account_mut::<Stake>(&localuniverse_api::ID)?;
+    stake.authority = *vault_info.key;
+    stake.balance = 0;
+    stake.rewards_factor = treasury.stake_rewards_factor;
+    stake.rewards = 0;
+    stake.lifetime_rewards = 0;
+    stake.compound_fee_reserve = 0;
+    stake.last_claim_at = 0;
+    stake.last_deposit_at = 0;
+    stake.last_withdraw_at = 0;
+    stake.unlock_at = 0;
+    stake.multiplier_bps = DENOMINATOR_BPS;
+    stake.weighted_balance = 0;
+    stake.id = VAULT_STAKE_ID;
+    stake.unbonding_balance = 0;
+    stake.unbonding_at = 0;
+    stake.sol_rewards_factor = treasury.stake_sol_rewards_factor;
+    stake.sol_rewards = 0;
+    stake.lifetime_sol_rewards = 0;
+    stake.claim_delegate = Pubkey::default();
+    stake.recipient = Pubkey::default();
+    stake.vote_locked_until = 0;
+
+    // Create the stake position's token account
+    create_associated_token_account(
+        signer_info,
+        stake_info,
+        stake_tokens_info,
+        mint_info,
+        system_program,
+        token_program,
+        associated_token_program,
+    )?;
+
+    Ok(())
+}
@@ -1,44 +1,130 @@
 mod automate;
 mod buyback;
+mod claim_all;
+mod cast_vote;
 mod checkpoint;
 mod claim_luxite;
+mod claim_referral;
 mod claim_sol;
+mod claim_stake_sol;
 mod claim_yield;
 mod close;
+mod close_many;
+mod close_miner;
+mod close_navigator;
+mod complete_redeem;
+mod complete_withdraw;
 mod compound_yield;
+mod create_pool;
+mod create_session;
 mod deploy;
 mod deposit;
 mod excavate;
+mod execute_proposal;
 mod fund_treasury;
 mod initialize;
+mod initialize_vault;
+mod migrate;
+mod open_season;
+mod pool_claim;
+mod pool_deposit;
+mod pool_harvest;
+mod poke_stake;
+mod pool_withdraw;
+mod propose;
+mod rank;
+mod refine;
+mod referral;
 mod reload_sol;
+mod revoke_session;
 mod scan;
+mod session;
 mod set_admin;
+mod set_deploy_caps;
+mod set_deploy_window;
+mod set_miner_delegate;
+mod set_miss_rebate;
+mod set_pool_dimension;
+mod set_referral_fee;
+mod set_referrer;
+mod set_unbonding_period;
+mod settle_season;
 mod tick;
+mod vault;
+mod vault_deposit;
+mod vault_redeem;
+mod veto_proposal;
+mod view_excavation;
+mod view_miner_rewards;
+mod view_stake_yield;
 mod withdraw;
 mod wrap;
 mod set_scan_fee;
+mod set_stake_boost;
+mod set_stake_delegate;
+mod set_stake_sol_share;
 
 use automate::*;
 use buyback::*;
+use claim_all::*;
+use cast_vote::*;
 use checkpoint::*;
 use claim_luxite::*;
+use claim_referral::*;
 use claim_sol::*;
+use claim_stake_sol::*;
 use claim_yield::*;
 use close::*;
+use close_many::*;
+use close_miner::*;
+use close_navigator::*;
+use complete_redeem::*;
+use complete_withdraw::*;
 use compound_yield::*;
+use create_pool::*;
+use create_session::*;
 use deploy::*;
 use deposit::*;
 use excavate::*;
+use execute_proposal::*;
 use fund_treasury::*;
 use initialize::*;
+use initialize_vault::*;
+use migrate::*;
+use open_season::*;
+use pool_claim::*;
+use pool_deposit::*;
+use pool_harvest::*;
+use poke_stake::*;
+use pool_withdraw::*;
+use propose::*;
+use refine::*;
 use reload_sol::*;
+use revoke_session::*;
 use scan::*;
 use set_admin::*;
+use set_deploy_caps::*;
+use set_deploy_window::*;
+use set_miner_delegate::*;
+use set_miss_rebate::*;
+use set_pool_dimension::*;
+use set_referral_fee::*;
+use set_referrer::*;
+use set_unbonding_period::*;
+use settle_season::*;
 use tick::*;
+use vault_deposit::*;
+use vault_redeem::*;
+use veto_proposal::*;
+use view_excavation::*;
+use view_miner_rewards::*;
+use view_stake_yield::*;
 use withdraw::*;
 use wrap::*;
 use set_scan_fee::*;
+use set_stake_boost::*;
+use set_stake_delegate::*;
+use set_stake_sol_share::*;
 
 use localuniverse_api::instruction::LocalUniverseInstruction;
 use solana_security_txt::security_txt;
@@ -54,6 +140,7 @@ pub fn process_instruction(
     match ix {
         // Dimension
         LocalUniverseInstruction::Scan => process_scan(accounts, data),
+        LocalUniverseInstruction::Refine => process_refine(accounts, data),
 
         // Drill
         LocalUniverseInstruction::Tick => process_tick(accounts, data),
@@ -65,16 +152,58 @@ pub fn process_instruction(
         LocalUniverseInstruction::ClaimLUXITE => process_claim_luxite(accounts, data),
         LocalUniverseInstruction::ClaimSOL => process_claim_sol(accounts, data),
         LocalUniverseInstruction::Close => process_close(accounts, data),
+        LocalUniverseInstruction::SetMinerDelegate => process_set_miner_delegate(accounts, data),
+        LocalUniverseInstruction::ClaimAll => process_claim_all(accounts, data),
+        LocalUniverseInstruction::CloseMiner => process_close_miner(accounts, data),
+        LocalUniverseInstruction::CloseMany => process_close_many(accounts, data),
 
         // Staker
         LocalUniverseInstruction::Deposit => process_deposit(accounts, data),
         LocalUniverseInstruction::Withdraw => process_withdraw(accounts, data),
         LocalUniverseInstruction::ClaimYield => process_claim_yield(accounts, data),
         LocalUniverseInstruction::CompoundYield => process_compound_yield(accounts, data),
+        LocalUniverseInstruction::CompleteWithdraw => process_complete_withdraw(accounts, data),
+        LocalUniverseInstruction::VaultDeposit => process_vault_deposit(accounts, data),
+        LocalUniverseInstruction::VaultRedeem => process_vault_redeem(accounts, data),
+        LocalUniverseInstruction::ClaimStakeSOL => process_claim_stake_sol(accounts, data),
+        LocalUniverseInstruction::SetStakeDelegate => process_set_stake_delegate(accounts, data),
+        LocalUniverseInstruction::PokeStake => process_poke_stake(accounts, data),
 
         // Automation
         LocalUniverseInstruction::Automate => process_automate(accounts, data),
         LocalUniverseInstruction::ReloadSOL => process_reload_sol(accounts, data),
+        LocalUniverseInstruction::CreateSession => process_create_session(accounts, data),
+        LocalUniverseInstruction::RevokeSession => process_revoke_session(accounts, data),
+
+        // Vault
+        LocalUniverseInstruction::CompleteRedeem => process_complete_redeem(accounts, data),
+
+        // Navigator
+        LocalUniverseInstruction::SetReferrer => process_set_referrer(accounts, data),
+        LocalUniverseInstruction::ClaimReferral => process_claim_referral(accounts, data),
+        LocalUniverseInstruction::CloseNavigator => process_close_navigator(accounts, data),
+
+        // Season
+        LocalUniverseInstruction::SettleSeason => process_settle_season(accounts, data),
+
+        // Governance
+        LocalUniverseInstruction::Propose => process_propose(accounts, data),
+        LocalUniverseInstruction::CastVote => process_cast_vote(accounts, data),
+        LocalUniverseInstruction::ExecuteProposal => process_execute_proposal(accounts, data),
+        LocalUniverseInstruction::VetoProposal => process_veto_proposal(accounts, data),
+
+        // Pool
+        LocalUniverseInstruction::CreatePool => process_create_pool(accounts, data),
+        LocalUniverseInstruction::SetPoolDimension => process_set_pool_dimension(accounts, data),
+        LocalUniverseInstruction::PoolDeposit => process_pool_deposit(accounts, data),
+        LocalUniverseInstruction::PoolWithdraw => process_pool_withdraw(accounts, data),
+        LocalUniverseInstruction::PoolHarvest => process_pool_harvest(accounts, data),
+        LocalUniverseInstruction::PoolClaim => process_pool_claim(accounts, data),
+
+        // View
+        LocalUniverseInstruction::ViewMinerRewards => process_view_miner_rewards(accounts, data),
+        LocalUniverseInstruction::ViewStakeYield => process_view_stake_yield(accounts, data),
+        LocalUniverseInstruction::ViewExcavation => process_view_excavation(accounts, data),
 
         // Admin
         LocalUniverseInstruction::Initialize => process_initialize(accounts, data),
@@ -83,6 +212,20 @@ pub fn process_instruction(
         LocalUniverseInstruction::Wrap => process_wrap(accounts, data),
         LocalUniverseInstruction::FundTreasury => process_fund_treasury(accounts, data),
         LocalUniverseInstruction::SetScanFee => process_set_scan_fee(accounts, data),
+        LocalUniverseInstruction::SetReferralFee => process_set_referral_fee(accounts, data),
+        LocalUniverseInstruction::OpenSeason => process_open_season(accounts, data),
+        LocalUniverseInstruction::SetMissRebate => process_set_miss_rebate(accounts, data),
+        LocalUniverseInstruction::SetDeployCaps => process_set_deploy_caps(accounts, data),
+        LocalUniverseInstruction::SetDeployWindow => process_set_deploy_window(accounts, data),
+        LocalUniverseInstruction::SetUnbondingPeriod => {
+            process_set_unbonding_period(accounts, data)
+        }
+        LocalUniverseInstruction::InitializeVault => process_initialize_vault(accounts, data),
+        LocalUniverseInstruction::SetStakeSOLShare => process_set_stake_sol_share(accounts, data),
+        LocalUniverseInstruction::SetStakeBoost => process_set_stake_boost(accounts, data),
+
+        // Migration
+        LocalUniverseInstruction::Migrate => process_migrate(accounts, data),
     }
 }
 
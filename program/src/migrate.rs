@@ -0,0 +1,211 @@
+use steel::*;
+use solana_program::rent::Rent;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+// Account sizes (including the discriminator) before their layouts grew.
+const LEGACY_CONFIG_SIZE: usize = 8 + 104;
+const LEGACY_DRILL_SIZE: usize = 8 + 64;
+const LEGACY_EXCAVATION_SIZE: usize = 8 + 152;
+const LEGACY_MINER_SIZE: usize = 8 + 184;
+const LEGACY_NAVIGATOR_SIZE: usize = 8 + 112;
+const LEGACY_STAKE_SIZE: usize = 8 + 128;
+const LEGACY_TREASURY_SIZE: usize = 8 + 112;
+
+/// Migrates accounts created before their layout grew. Each trailing account is reallocated
+/// to its current size, with rent topped up by the signer, and its new fields are set to the
+/// defaults a freshly created account would have. Dimensions scanned before the dimension
+/// index existed are recorded on the index page at `Config.dimension_count`. Accounts that
+/// are already current are skipped, so anyone may call this for any account.
+pub fn process_migrate(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    if accounts.len() < 4 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(4);
+
+    let [signer_info, config_info, dimension_index_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    config_info
+        .is_writable()?
+        .has_owner(&localuniverse_api::ID)?
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?;
+
+    system_program.is_program(&system_program::ID)?;
+
+    // The config is migrated first, as dimensions are counted on it
+    for account_info in std::iter::once(config_info).chain(remaining_accounts) {
+        account_info
+            .is_writable()?
+            .has_owner(&localuniverse_api::ID)?;
+
+        let discriminator = account_info.try_borrow_data()?[0];
+        let account_type = LocalUniverseAccount::try_from(discriminator)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        match account_type {
+            LocalUniverseAccount::Config => {
+                if !grow::<Config>(account_info, LEGACY_CONFIG_SIZE, signer_info, system_program)? {
+                    continue;
+                }
+                let config = account_info.as_account_mut::<Config>(&localuniverse_api::ID)?;
+                config.next_dimension_id = AUTO_DIMENSION_ID_START;
+                config.dimension_count = 0;
+                config.referral_fee_bps = REFERRAL_FEE_BPS;
+                config.miss_rebate_bps = MISS_REBATE_BPS;
+                config.miss_rebate_luxite_rate = 0;
+                config.max_miner_deploy = 0;
+                config.max_excavation_deploy = 0;
+                config.luxite_curve = LUXITE_CURVE_LINEAR;
+                config.deploy_cutoff_slots = 0;
+                config.late_deploy_decay_bps = 0;
+                config.unbonding_period = UNBONDING_PERIOD;
+                config.stake_sol_share_bps = STAKE_SOL_SHARE_BPS;
+                config.stake_boost_max_bps = STAKE_BOOST_MAX_BPS;
+                config.stake_boost_half_balance = STAKE_BOOST_HALF_BALANCE;
+                config.proposal_count = 0;
+                config.admin_veto_until = clock.unix_timestamp + GOVERNANCE_BOOTSTRAP_PERIOD;
+            }
+            LocalUniverseAccount::Treasury => {
+                if !grow::<Treasury>(account_info, LEGACY_TREASURY_SIZE, signer_info, system_program)? {
+                    continue;
+                }
+                // Legacy stakes migrate with a 1x multiplier
+                let treasury = account_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+                treasury.total_weighted_stake = treasury.total_staked;
+                treasury.stake_sol_rewards_factor = Numeric::ZERO;
+                treasury.stake_sol_balance = 0;
+            }
+            LocalUniverseAccount::Dimension => {
+                let dimension = account_info.as_account_mut::<Dimension>(&localuniverse_api::ID)?;
+                if dimension.indexed > 0 {
+                    continue;
+                }
+
+                // Dimensions past the end of the given page are left for a later call
+                let config = config_info.as_account_mut::<Config>(&localuniverse_api::ID)?;
+                let page = DimensionIndex::page_of(config.dimension_count);
+                if *dimension_index_info.key != dimension_index_pda(page).0 {
+                    continue;
+                }
+                dimension_index_info.is_writable()?;
+
+                if dimension_index_info.data_is_empty() {
+                    create_program_account::<DimensionIndex>(
+                        dimension_index_info,
+                        system_program,
+                        signer_info,
+                        &localuniverse_api::ID,
+                        &[DIMENSION_INDEX, &page.to_le_bytes()],
+                    )?;
+
+                    let dimension_index = dimension_index_info.as_account_mut::<DimensionIndex>(&localuniverse_api::ID)?;
+                    dimension_index.page = page;
+                    dimension_index.count = 0;
+                }
+
+                let dimension_index = dimension_index_info.as_account_mut::<DimensionIndex>(&localuniverse_api::ID)?;
+                dimension_index.push(dimension.id);
+                config.dimension_count += 1;
+                dimension.indexed = 1;
+            }
+            LocalUniverseAccount::Drill => {
+                // Hit history starts empty
+                grow::<Drill>(account_info, LEGACY_DRILL_SIZE, signer_info, system_program)?;
+            }
+            LocalUniverseAccount::Excavation => {
+                if !grow::<Excavation>(account_info, LEGACY_EXCAVATION_SIZE, signer_info, system_program)? {
+                    continue;
+                }
+                // Legacy excavations split LUXITE linearly by deployed SOL
+                let excavation = account_info.as_account_mut::<Excavation>(&localuniverse_api::ID)?;
+                excavation.total_weight = excavation.total_deployed;
+                excavation.luxite_curve = LUXITE_CURVE_LINEAR;
+                excavation.conditional_deployed = 0;
+                excavation.opened_at = 0;
+            }
+            LocalUniverseAccount::Miner => {
+                if !grow::<Miner>(account_info, LEGACY_MINER_SIZE, signer_info, system_program)? {
+                    continue;
+                }
+                let miner = account_info.as_account_mut::<Miner>(&localuniverse_api::ID)?;
+                miner.weight = miner.deployed;
+                miner.weighted_deployed = miner.deployed;
+                miner.min_total = 0;
+                miner.claim_delegate = Pubkey::default();
+                miner.recipient = Pubkey::default();
+                miner.buffer_d = 0;
+            }
+            LocalUniverseAccount::Navigator => {
+                // Referral, rank and season stats start at zero. Open miners were never counted,
+                // so the navigator cannot be closed
+                grow::<Navigator>(account_info, LEGACY_NAVIGATOR_SIZE, signer_info, system_program)?;
+            }
+            LocalUniverseAccount::Stake => {
+                if !grow::<Stake>(account_info, LEGACY_STAKE_SIZE, signer_info, system_program)? {
+                    continue;
+                }
+                // Legacy stakes are unlocked position 0, earning on their full balance
+                let stake = account_info.as_account_mut::<Stake>(&localuniverse_api::ID)?;
+                stake.unlock_at = 0;
+                stake.multiplier_bps = DENOMINATOR_BPS;
+                stake.weighted_balance = stake.balance;
+                stake.id = 0;
+                stake.unbonding_balance = 0;
+                stake.unbonding_at = 0;
+                stake.sol_rewards_factor = Numeric::ZERO;
+                stake.sol_rewards = 0;
+                stake.lifetime_sol_rewards = 0;
+                stake.claim_delegate = Pubkey::default();
+                stake.recipient = Pubkey::default();
+                stake.vote_locked_until = 0;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Grows a legacy account to the current size of `T`, funding the extra rent from the payer.
+/// Returns false if the account is already current.
+fn grow<'info, T: Pod>(
+    account_info: &AccountInfo<'info>,
+    legacy_size: usize,
+    payer_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<bool, ProgramError> {
+    let size = 8 + std::mem::size_of::<T>();
+    let len = account_info.data_len();
+    if len == size {
+        return Ok(false);
+    }
+    if len != legacy_size {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let rent = Rent::get()?.minimum_balance(size);
+    let lamports = account_info.lamports();
+    if rent > lamports {
+        solana_program::program::invoke(
+            &solana_program::system_instruction::transfer(
+                payer_info.key,
+                account_info.key,
+                rent - lamports,
+            ),
+            &[payer_info.clone(), account_info.clone(), system_program.clone()],
+        )?;
+    }
+
+    account_info.realloc(size, true)?;
+
+    Ok(true)
+}
@@ -0,0 +1,75 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Opens a season and reserves its LUXITE prize pool from the treasury (admin only).
+pub fn process_open_season(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = OpenSeason::try_from_bytes(data)?;
+    let season_id = u64::from_le_bytes(args.season_id);
+    let metric = u64::from_le_bytes(args.metric);
+    let prize_pool = u64::from_le_bytes(args.prize_pool);
+
+    let [signer_info, config_info, grid_info, season_info, treasury_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    config_info
+        .as_account::<Config>(&localuniverse_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    let grid = grid_info
+        .has_seeds(&[GRID], &localuniverse_api::ID)?
+        .as_account::<Grid>(&localuniverse_api::ID)?;
+
+    season_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[SEASON, &season_id.to_le_bytes()], &localuniverse_api::ID)?;
+
+    treasury_info
+        .is_type::<Treasury>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?;
+
+    system_program.is_program(&system_program::ID)?;
+
+    assert!(season_id >= grid.epoch_id, "Season already ended");
+    assert!(
+        metric == SEASON_METRIC_DEPLOYED || metric == SEASON_METRIC_MINED,
+        "Invalid season metric"
+    );
+
+    // Reserve prize pool from emissions
+    let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+    assert!(treasury.luxite_balance >= prize_pool, "Insufficient treasury balance");
+    treasury.luxite_balance -= prize_pool;
+
+    // Create season account
+    create_program_account::<Season>(
+        season_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[SEASON, &season_id.to_le_bytes()],
+    )?;
+
+    let season = season_info.as_account_mut::<Season>(&localuniverse_api::ID)?;
+    season.id = season_id;
+    season.metric = metric;
+    season.prize_pool = prize_pool;
+    season.settled = 0;
+    season.leaderboard = [SeasonEntry::default(); SEASON_LEADERBOARD_SIZE];
+    season.buffer_a = 0;
+    season.buffer_b = 0;
+
+    Ok(())
+}
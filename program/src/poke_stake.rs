@@ -0,0 +1,41 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Drops the multiplier of a stake position whose lock has expired, so it stops earning a
+/// boosted share of yield. Anyone may call this for any position.
+pub fn process_poke_stake(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = PokeStake::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
+    let clock = Clock::get()?;
+
+    let [signer_info, stake_info, treasury_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate stake - must hold an expired boost
+    let stake = stake_info
+        .is_writable()?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?
+        .assert_mut(|s| s.id == id)?
+        .assert_mut(|s| s.multiplier_bps > DENOMINATOR_BPS)?
+        .assert_mut(|s| !s.is_locked(&clock))?;
+
+    // Validate treasury
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    // Settle rewards at the boosted weight, then drop the multiplier
+    stake.update_rewards(treasury);
+    stake.sync_weight(&clock, treasury);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Claims a member's accrued pool rewards. SOL is sent to the signer and LUXITE to the signer's
+/// token account, which is created if needed.
+pub fn process_pool_claim(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [signer_info, pool_info, member_info, mint_info, recipient_tokens_info, pool_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let pool = pool_info
+        .is_writable()?
+        .as_account_mut::<Pool>(&localuniverse_api::ID)?;
+    let authority = pool.authority;
+    let id = pool.id;
+    pool_info.has_seeds(
+        &[POOL, authority.as_ref(), &id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    let member = member_info
+        .is_writable()?
+        .has_seeds(
+            &[POOL_MEMBER, pool_info.key.as_ref(), signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<PoolMember>(&localuniverse_api::ID)?;
+
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    // Claim rewards
+    let (sol, luxite) = member.claim(pool);
+
+    // Transfer SOL from pool to member
+    pool_info.send(sol, signer_info);
+
+    if luxite == 0 {
+        return Ok(());
+    }
+
+    pool_tokens_info
+        .is_writable()?
+        .as_associated_token_account(pool_info.key, mint_info.key)?;
+
+    // Create recipient token account if needed
+    if recipient_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            signer_info,
+            recipient_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_tokens_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+    }
+
+    // Transfer LUXITE from pool to member
+    transfer_signed(
+        pool_info,
+        pool_tokens_info,
+        recipient_tokens_info,
+        token_program,
+        luxite,
+        &[POOL, authority.as_ref(), &id.to_le_bytes()],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Deposits SOL into a mining pool for shares, creating the signer's member account if needed.
+pub fn process_pool_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = PoolDeposit::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    let clock = Clock::get()?;
+
+    let [signer_info, pool_info, member_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate pool
+    let pool = pool_info
+        .is_writable()?
+        .as_account_mut::<Pool>(&localuniverse_api::ID)?;
+    pool_info.has_seeds(
+        &[POOL, pool.authority.as_ref(), &pool.id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    // Validate member
+    member_info
+        .is_writable()?
+        .has_seeds(
+            &[POOL_MEMBER, pool_info.key.as_ref(), signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+
+    assert!(amount > 0, "Amount must be greater than zero");
+    assert!(!pool.is_deployed(), "Pool has SOL deployed");
+
+    // Create member account if new
+    let member = if member_info.data_is_empty() {
+        create_program_account::<PoolMember>(
+            member_info,
+            system_program,
+            signer_info,
+            &localuniverse_api::ID,
+            &[POOL_MEMBER, pool_info.key.as_ref(), signer_info.key.as_ref()],
+        )?;
+
+        let member = member_info.as_account_mut::<PoolMember>(&localuniverse_api::ID)?;
+        member.authority = *signer_info.key;
+        member.pool = *pool_info.key;
+        member.shares = 0;
+        member.sol_rewards_factor = pool.sol_rewards_factor;
+        member.luxite_rewards_factor = pool.luxite_rewards_factor;
+        member.rewards_sol = 0;
+        member.rewards_luxite = 0;
+        member.lifetime_rewards_sol = 0;
+        member.lifetime_rewards_luxite = 0;
+        member.last_deposit_at = 0;
+        member
+    } else {
+        member_info.as_account_mut::<PoolMember>(&localuniverse_api::ID)?
+    };
+
+    // Mint shares
+    member.deposit(amount, &clock, pool);
+
+    // Transfer SOL into the pool
+    pool_info.collect(amount, signer_info)?;
+
+    Ok(())
+}
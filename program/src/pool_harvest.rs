@@ -0,0 +1,125 @@
+use steel::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Claims a pool miner's checkpointed SOL and LUXITE into the pool. Deployed SOL returns to the
+/// pool's balance and the rest is distributed to members per share. Anyone may sign; the
+/// signer funds the pool's token account if needed. Harvesting the pool's current excavation
+/// frees it for deposits and withdrawals.
+pub fn process_pool_harvest(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    let [signer_info, pool_info, miner_info, navigator_info, mint_info, pool_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    // Validate pool
+    let pool = pool_info
+        .is_writable()?
+        .as_account_mut::<Pool>(&localuniverse_api::ID)?;
+    pool_info.has_seeds(
+        &[POOL, pool.authority.as_ref(), &pool.id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    // Validate miner
+    miner_info
+        .is_type::<Miner>(&localuniverse_api::ID)?
+        .is_writable()?;
+
+    let miner = miner_info
+        .as_account_mut::<Miner>(&localuniverse_api::ID)?
+        .assert_mut(|m| m.authority == *pool_info.key)?
+        .assert_mut(|m| m.checkpoint_id == m.excavation_id)?;
+
+    miner_info.has_seeds(
+        &[MINER, &miner.dimension_id.to_le_bytes(), pool_info.key.as_ref()],
+        &localuniverse_api::ID,
+    )?;
+
+    navigator_info
+        .is_type::<Navigator>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, pool_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    treasury_info
+        .is_type::<Treasury>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?;
+
+    treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
+
+    // Create pool token account if needed
+    if pool_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            pool_info,
+            pool_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        pool_tokens_info.as_associated_token_account(pool_info.key, mint_info.key)?;
+    }
+
+    // Claim miner rewards
+    let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+    let luxite = miner.claim_luxite(&clock, treasury);
+    let sol = miner.claim_sol(&clock);
+
+    // Update navigator lifetime stats
+    let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    navigator.lifetime_rewards_luxite += luxite;
+    navigator.lifetime_rewards_sol += sol;
+
+    // Free the pool once its excavation is settled, returning the deployed SOL to the balance
+    let profit = if pool.is_deployed()
+        && miner.dimension_id == pool.dimension_id
+        && miner.excavation_id == pool.excavation_id
+    {
+        pool.settle(sol, clock.slot)
+    } else {
+        sol
+    };
+
+    // Distribute to members
+    pool.distribute(profit, luxite);
+
+    // Transfer SOL from miner account to pool
+    miner_info.send(sol, pool_info);
+
+    // Transfer LUXITE from treasury to pool
+    if luxite > 0 {
+        transfer_signed(
+            treasury_info,
+            treasury_tokens_info,
+            pool_tokens_info,
+            token_program,
+            luxite,
+            &[TREASURY],
+        )?;
+    }
+
+    sol_log(&format!(
+        "Harvested {} SOL and {} LUXITE",
+        lamports_to_sol(sol),
+        luxite
+    ));
+
+    Ok(())
+}
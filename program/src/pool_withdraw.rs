@@ -0,0 +1,44 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Redeems pool shares for their part of the pool's idle SOL. The pool must be idle.
+pub fn process_pool_withdraw(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = PoolWithdraw::try_from_bytes(data)?;
+    let shares = u64::from_le_bytes(args.shares);
+
+    let [signer_info, pool_info, member_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let pool = pool_info
+        .is_writable()?
+        .as_account_mut::<Pool>(&localuniverse_api::ID)?;
+    pool_info.has_seeds(
+        &[POOL, pool.authority.as_ref(), &pool.id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    let member = member_info
+        .is_writable()?
+        .has_seeds(
+            &[POOL_MEMBER, pool_info.key.as_ref(), signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<PoolMember>(&localuniverse_api::ID)?;
+
+    assert!(!pool.is_deployed(), "Pool has SOL deployed");
+
+    // Burn shares
+    let amount = member.withdraw(shares, pool);
+
+    // Transfer SOL to the member
+    pool_info.send(amount, signer_info);
+
+    Ok(())
+}
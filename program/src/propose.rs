@@ -0,0 +1,79 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Creates a governance proposal to change a config parameter. The proposer must hold a stake
+/// position with at least PROPOSAL_MIN_STAKE.
+pub fn process_propose(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = Propose::try_from_bytes(data)?;
+    let stake_id = u64::from_le_bytes(args.stake_id);
+    let param = u64::from_le_bytes(args.param);
+    let value = u64::from_le_bytes(args.value);
+
+    let clock = Clock::get()?;
+
+    let [signer_info, config_info, proposal_info, stake_info, treasury_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account_mut::<Config>(&localuniverse_api::ID)?;
+
+    let proposal_id = config.proposal_count;
+    proposal_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[PROPOSAL, &proposal_id.to_le_bytes()], &localuniverse_api::ID)?;
+
+    stake_info
+        .has_seeds(
+            &stake_seeds(signer_info.key.as_ref(), &stake_id.to_le_bytes()),
+            &localuniverse_api::ID,
+        )?
+        .as_account::<Stake>(&localuniverse_api::ID)?
+        .assert(|s| s.balance >= PROPOSAL_MIN_STAKE)?;
+
+    let treasury = treasury_info
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account::<Treasury>(&localuniverse_api::ID)?;
+
+    system_program.is_program(&system_program::ID)?;
+
+    // Reject changes the admin setters would reject
+    let mut preview = *config;
+    assert!(preview.set_param(param, value), "Invalid parameter");
+
+    // Create proposal account
+    create_program_account::<Proposal>(
+        proposal_info,
+        system_program,
+        signer_info,
+        &localuniverse_api::ID,
+        &[PROPOSAL, &proposal_id.to_le_bytes()],
+    )?;
+
+    let proposal = proposal_info.as_account_mut::<Proposal>(&localuniverse_api::ID)?;
+    proposal.id = proposal_id;
+    proposal.proposer = *signer_info.key;
+    proposal.param = param;
+    proposal.value = value;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.voting_ends_at = clock.unix_timestamp + GOVERNANCE_VOTING_PERIOD;
+    proposal.executable_at = proposal.voting_ends_at + GOVERNANCE_TIMELOCK;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.quorum = Proposal::quorum_of(treasury.total_staked);
+    proposal.executed = 0;
+    proposal.vetoed = 0;
+
+    config.proposal_count += 1;
+
+    Ok(())
+}
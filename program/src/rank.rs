@@ -0,0 +1,21 @@
+use steel::*;
+
+use localuniverse_api::{
+    event::*,
+    state::*,
+};
+
+/// Recomputes a navigator's XP and rank, logging a RankUpEvent if the rank increased.
+pub fn update_rank(navigator: &mut Navigator, clock: &Clock) {
+    if navigator.update_rank() {
+        RankUpEvent {
+            disc: LocalUniverseEvent::RankUp as u64,
+            authority: navigator.authority,
+            rank: navigator.rank,
+            xp: navigator.xp,
+            badges: navigator.badges,
+            ts: clock.unix_timestamp,
+        }
+        .log();
+    }
+}
@@ -0,0 +1,44 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Resolves the referrer's navigator for a referee. Returns None if the referee has no
+/// referrer or the referrer's navigator has been closed.
+pub fn referrer_navigator<'a, 'info>(
+    navigator: &Navigator,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+    if !navigator.has_referrer() {
+        return Ok(None);
+    }
+
+    let Some(referrer_info) = remaining_accounts.first() else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    referrer_info
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, navigator.referrer.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    if referrer_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    referrer_info.is_type::<Navigator>(&localuniverse_api::ID)?;
+
+    Ok(Some(referrer_info))
+}
+
+/// Returns the referral share of a fee.
+pub fn referral_share(fee: u64, config: &Config) -> u64 {
+    fee.checked_mul(config.referral_fee_bps.min(DENOMINATOR_BPS))
+        .unwrap()
+        .checked_div(DENOMINATOR_BPS)
+        .unwrap()
+}
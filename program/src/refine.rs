@@ -0,0 +1,193 @@
+use steel::*;
+use solana_program::{log::sol_log, sysvar::slot_hashes};
+
+use localuniverse_api::{
+    consts::*,
+    error::*,
+    instruction::*,
+    state::*,
+    event::*,
+};
+
+use crate::scan::roll_richness;
+
+/// Refines a dimension for a fee, at most once per REFINE_COOLDOWN. Restores part of the
+/// richness lost to depletion, or re-rolls the richness entirely (dimension authority only).
+///
+/// A re-roll is committed to the hash of the current tick's end slot, which must still be in
+/// the future when the fee is paid. It is applied by the next Excavate of the dimension, after
+/// the tick's excavation has been processed on the old richness, or by anyone calling Refine
+/// again once the slot has passed. A commitment whose slot hash has left the SlotHashes sysvar lapses.
+pub fn process_refine(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = Refine::try_from_bytes(data)?;
+    let reroll = u64::from_le_bytes(args.reroll) > 0;
+
+    let [signer_info, config_info, grid_info, dimension_info, drill_info, fee_collector_info, system_program, slot_hashes_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    config_info
+        .is_type::<Config>(&localuniverse_api::ID)?
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?;
+
+    let config = config_info.as_account::<Config>(&localuniverse_api::ID)?;
+
+    let grid = grid_info
+        .has_seeds(&[GRID], &localuniverse_api::ID)?
+        .as_account::<Grid>(&localuniverse_api::ID)?;
+
+    fee_collector_info
+        .is_writable()?
+        .has_address(&config.fee_collector)?;
+
+    slot_hashes_info.has_address(&slot_hashes::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    dimension_info
+        .is_type::<Dimension>(&localuniverse_api::ID)?
+        .is_writable()?;
+
+    let dimension = dimension_info.as_account_mut::<Dimension>(&localuniverse_api::ID)?;
+    let dimension_id = dimension.id;
+
+    dimension_info.has_seeds(
+        &[DIMENSION, &dimension_id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    let drill = drill_info
+        .has_seeds(
+            &[DRILL, &dimension_id.to_le_bytes()],
+            &localuniverse_api::ID,
+        )?
+        .as_account::<Drill>(&localuniverse_api::ID)?;
+
+    let clock = Clock::get()?;
+
+    // Apply a pending re-roll
+    if reroll && dimension.reroll_slot > 0 {
+        if clock.slot <= dimension.reroll_slot {
+            return Err(LocalUniverseError::RerollPending.into());
+        }
+        return apply_reroll(dimension, drill.depth, signer_info.key, slot_hashes_info, &clock);
+    }
+
+    if clock.unix_timestamp < dimension.refined_at + REFINE_COOLDOWN {
+        return Err(LocalUniverseError::RefineCooldown.into());
+    }
+
+    // Only the dimension authority may gamble the richness away
+    if reroll && dimension.authority != *signer_info.key {
+        return Err(LocalUniverseError::NotAuthorized.into());
+    }
+
+    // Once the end slot is reached its hash is known, so the re-roll could be chosen
+    if reroll && clock.slot >= grid.end_slot {
+        return Err(LocalUniverseError::TickEnded.into());
+    }
+
+    // Transfer refine fee
+    solana_program::program::invoke(
+        &solana_program::system_instruction::transfer(
+            signer_info.key,
+            fee_collector_info.key,
+            DIMENSION_REFINE_FEE,
+        ),
+        &[signer_info.clone(), fee_collector_info.clone()],
+    )?;
+
+    if reroll {
+        // Commit to a slot hash nobody knows yet
+        dimension.reroll_slot = grid.end_slot;
+        sol_log(&format!(
+            "Re-roll of dimension {} committed to slot {}",
+            dimension_id, grid.end_slot
+        ));
+        return Ok(());
+    }
+
+    // Restore part of the depletion accumulated since the last refine
+    let depleted = drill.depth.saturating_sub(dimension.depth_offset);
+    dimension.depth_offset += depleted * REFINE_RESTORE_BPS / DENOMINATOR_BPS;
+    dimension.refined_at = clock.unix_timestamp;
+
+    RefineEvent {
+        disc: LocalUniverseEvent::Refine as u64,
+        dimension_id,
+        refiner: *signer_info.key,
+        reroll: 0,
+        richness: dimension.richness as u64,
+        effective_richness: dimension.richness_at(drill.depth),
+        ts: clock.unix_timestamp,
+    }
+    .log();
+
+    Ok(())
+}
+
+/// Applies a pending re-roll once its committed slot has passed, rolling the richness from
+/// that slot's hash and resetting depletion at the given drill depth.
+pub fn apply_reroll(
+    dimension: &mut Dimension,
+    depth: u64,
+    refiner: &Pubkey,
+    slot_hashes_info: &AccountInfo,
+    clock: &Clock,
+) -> ProgramResult {
+    if dimension.reroll_slot == 0 || clock.slot <= dimension.reroll_slot {
+        return Ok(());
+    }
+
+    let reroll_slot = dimension.reroll_slot;
+    dimension.reroll_slot = 0;
+
+    let slot_hashes_data = slot_hashes_info.try_borrow_data()?;
+    let Some(hash) = slot_hash_at(&slot_hashes_data, reroll_slot) else {
+        sol_log(&format!("Re-roll slot {} expired", reroll_slot));
+        return Ok(());
+    };
+
+    dimension.richness = roll_richness(hash);
+    dimension.depth_offset = depth;
+    dimension.refined_at = clock.unix_timestamp;
+
+    RefineEvent {
+        disc: LocalUniverseEvent::Refine as u64,
+        dimension_id: dimension.id,
+        refiner: *refiner,
+        reroll: 1,
+        richness: dimension.richness as u64,
+        effective_richness: dimension.richness_at(depth),
+        ts: clock.unix_timestamp,
+    }
+    .log();
+
+    Ok(())
+}
+
+/// Returns the hash of the first slot at or after `slot` from SlotHashes sysvar data, or None
+/// if that can no longer be proven because older entries have been dropped.
+fn slot_hash_at(slot_hashes_data: &[u8], slot: u64) -> Option<&[u8]> {
+    let len = u64::from_le_bytes(slot_hashes_data[0..8].try_into().unwrap()) as usize;
+    let mut candidate = None;
+
+    // Entries are ordered from newest to oldest
+    for i in 0..len {
+        let start = 8 + i * 40;
+        let entry_slot = u64::from_le_bytes(slot_hashes_data[start..start + 8].try_into().unwrap());
+        let hash = &slot_hashes_data[start + 8..start + 40];
+        if entry_slot < slot {
+            return candidate;
+        }
+        if entry_slot == slot {
+            return Some(hash);
+        }
+        candidate = Some(hash);
+    }
+
+    None
+}
@@ -0,0 +1,30 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Revokes a session key, returning its unspent escrow and rent to the authority.
+pub fn process_revoke_session(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [signer_info, session_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate session
+    let session = session_info
+        .is_writable()?
+        .as_account::<SessionKey>(&localuniverse_api::ID)?
+        .assert(|s| s.authority == *signer_info.key)?;
+    session_info.has_seeds(
+        &[SESSION, signer_info.key.as_ref(), session.key.as_ref()],
+        &localuniverse_api::ID,
+    )?;
+
+    // Close session
+    session_info.close(signer_info)?;
+
+    Ok(())
+}
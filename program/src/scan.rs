@@ -3,17 +3,27 @@ use solana_program::sysvar::slot_hashes;
 
 use localuniverse_api::{
     consts::*,
+    error::*,
     instruction::*,
     state::*,
     event::*,
 };
 
-/// Scans to discover a new dimension. Creates Dimension and Drill accounts.
+use crate::{rank::*, referral::*};
+
+/// Scans to discover a new dimension. Creates Dimension and Drill accounts and records the
+/// dimension in the index. Pass AUTO_DIMENSION_ID to be assigned the next sequential ID. Only
+/// the admin may choose an ID below AUTO_DIMENSION_ID_START, so IDs cannot be squatted.
 pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let args = Scan::try_from_bytes(data)?;
-    let dimension_id = u64::from_le_bytes(args.dimension_id);
+    let requested_id = u64::from_le_bytes(args.dimension_id);
+
+    if accounts.len() < 9 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(9);
 
-    let [signer_info, config_info, dimension_info, drill_info, navigator_info, fee_collector_info, system_program, slot_hashes_info] =
+    let [signer_info, config_info, dimension_info, drill_info, navigator_info, dimension_index_info, fee_collector_info, system_program, slot_hashes_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -23,21 +33,35 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     config_info
         .is_type::<Config>(&localuniverse_api::ID)?
+        .is_writable()?
         .has_seeds(&[CONFIG], &localuniverse_api::ID)?;
 
-    let config = config_info.as_account::<Config>(&localuniverse_api::ID)?;
+    let config = config_info.as_account_mut::<Config>(&localuniverse_api::ID)?;
 
     fee_collector_info.has_address(&config.fee_collector)?;
     slot_hashes_info.has_address(&slot_hashes::ID)?;
 
+    // Resolve the dimension ID
+    let is_auto = requested_id == AUTO_DIMENSION_ID;
+    let dimension_id = if is_auto {
+        config.next_dimension_id
+    } else if requested_id < AUTO_DIMENSION_ID_START && config.admin == *signer_info.key {
+        requested_id
+    } else {
+        return Err(LocalUniverseError::InvalidDimensionId.into());
+    };
+
     dimension_info
-        .is_empty()?
         .is_writable()?
         .has_seeds(
             &[DIMENSION, &dimension_id.to_le_bytes()],
             &localuniverse_api::ID,
         )?;
 
+    if !dimension_info.data_is_empty() {
+        return Err(LocalUniverseError::DimensionExists.into());
+    }
+
     drill_info
         .is_empty()?
         .is_writable()?
@@ -46,6 +70,14 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
             &localuniverse_api::ID,
         )?;
 
+    let page = DimensionIndex::page_of(config.dimension_count);
+    dimension_index_info
+        .is_writable()?
+        .has_seeds(
+            &[DIMENSION_INDEX, &page.to_le_bytes()],
+            &localuniverse_api::ID,
+        )?;
+
     navigator_info
         .is_writable()?
         .has_seeds(
@@ -53,66 +85,66 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
             &localuniverse_api::ID,
         )?;
 
+    let clock = Clock::get()?;
+
+    // Split referral share from the scan fee
+    let referrer_info = if navigator_info.data_is_empty() {
+        None
+    } else {
+        navigator_info.is_type::<Navigator>(&localuniverse_api::ID)?;
+        referrer_navigator(
+            navigator_info.as_account::<Navigator>(&localuniverse_api::ID)?,
+            remaining_accounts,
+        )?
+    };
+    let referral_fee = if referrer_info.is_some() {
+        referral_share(config.scan_fee, config)
+    } else {
+        0
+    };
+    let protocol_fee = config.scan_fee - referral_fee;
+
     // Transfer scan fee
-    if config.scan_fee > 0 {
+    if protocol_fee > 0 {
         solana_program::program::invoke(
             &solana_program::system_instruction::transfer(
                 signer_info.key,
                 fee_collector_info.key,
-                config.scan_fee,
+                protocol_fee,
             ),
             &[signer_info.clone(), fee_collector_info.clone()],
         )?;
     }
 
-    // Generate richness from slot hash
-    let slot_hashes_data = slot_hashes_info.try_borrow_data()?;
-    let hash_start = 8 + 8;
+    if let Some(referrer_info) = referrer_info {
+        if referral_fee > 0 {
+            solana_program::program::invoke(
+                &solana_program::system_instruction::transfer(
+                    signer_info.key,
+                    referrer_info.key,
+                    referral_fee,
+                ),
+                &[signer_info.clone(), referrer_info.clone()],
+            )?;
 
-    // Use different parts of the hash for tier vs range
-    let tier_bytes = &slot_hashes_data[hash_start..hash_start + 4];
-    let range_bytes = &slot_hashes_data[hash_start + 4..hash_start + 8];
+            let referrer = referrer_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+            referrer.referral_rewards_sol += referral_fee;
+            referrer.lifetime_referral_rewards_sol += referral_fee;
 
-    let tier_roll = u32::from_le_bytes([
-        tier_bytes[0],
-        tier_bytes[1],
-        tier_bytes[2],
-        tier_bytes[3],
-    ]) % 10000;
-
-    let range_roll = u32::from_le_bytes([
-        range_bytes[0],
-        range_bytes[1],
-        range_bytes[2],
-        range_bytes[3],
-    ]);
-
-    // Richness determines hit chance (higher = harder to hit)
-    // Roll must be > richness to hit, so:
-    // - richness 900M = 10% hit chance
-    // - richness 500M = 50% hit chance  
-    // - richness 100M = 90% hit chance
-    let richness: u32 = if tier_roll < 8000 {
-        // 80% of dimensions: 750M-1B richness (0-25% hit chance)
-        750_000_000 + (range_roll % 250_000_001)
-    } else if tier_roll < 9500 {
-        // 15% of dimensions: 500M-750M richness (25-50% hit chance)
-        500_000_000 + (range_roll % 250_000_001)
-    } else if tier_roll < 9900 {
-        // 4% of dimensions: 250M-500M richness (50-75% hit chance)
-        250_000_000 + (range_roll % 250_000_001)
-    } else if tier_roll < 9990 {
-        // 0.9% of dimensions: 100M-250M richness (75-90% hit chance)
-        100_000_000 + (range_roll % 150_000_001)
-    } else if tier_roll < 9999 {
-        // 0.09% of dimensions: 20M-100M richness (90-98% hit chance)
-        20_000_000 + (range_roll % 80_000_001)
-    } else {
-        // 0.01% of dimensions: 0-20M richness (98-100% hit chance)
-        range_roll % 20_000_001
-    };
+            ReferralRewardEvent {
+                disc: LocalUniverseEvent::ReferralReward as u64,
+                referrer: referrer.authority,
+                referee: *signer_info.key,
+                amount: referral_fee,
+                source: 1,
+                ts: clock.unix_timestamp,
+            }
+            .log();
+        }
+    }
 
-    let clock = Clock::get()?;
+    // Generate richness from slot hash
+    let richness = roll_richness(&slot_hashes_info.try_borrow_data()?[16..48]);
 
     // Create dimension account
     create_program_account::<Dimension>(
@@ -129,8 +161,12 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     dimension.id = dimension_id;
     dimension.richness = richness;
     dimension.scanned_at = clock.unix_timestamp;
+    dimension.depth_offset = 0;
+    dimension.refined_at = 0;
+    dimension.reroll_slot = 0;
+    dimension.indexed = 1;
 
-    // Create drill account (lifetime stats only)
+    // Create drill account (lifetime stats and hit history)
     create_program_account::<Drill>(
         drill_info,
         system_program,
@@ -144,11 +180,40 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     drill.depth = 0;
     drill.lifetime_deployed = 0;
     drill.lifetime_rewards_luxite = 0;
+    drill.hits = 0;
+    drill.misses = 0;
+    drill.streak = 0;
+    drill.longest_hit_streak = 0;
+    drill.longest_miss_streak = 0;
+    drill.history = [0; 4];
     drill.buffer_a = 0;
     drill.buffer_b = 0;
     drill.buffer_c = 0;
     drill.buffer_d = 0;
 
+    // Record dimension in the index
+    if dimension_index_info.data_is_empty() {
+        create_program_account::<DimensionIndex>(
+            dimension_index_info,
+            system_program,
+            signer_info,
+            &localuniverse_api::ID,
+            &[DIMENSION_INDEX, &page.to_le_bytes()],
+        )?;
+
+        let dimension_index = dimension_index_info.as_account_mut::<DimensionIndex>(&localuniverse_api::ID)?;
+        dimension_index.page = page;
+        dimension_index.count = 0;
+    }
+
+    let dimension_index = dimension_index_info.as_account_mut::<DimensionIndex>(&localuniverse_api::ID)?;
+    dimension_index.push(dimension_id);
+
+    config.dimension_count += 1;
+    if is_auto {
+        config.next_dimension_id += 1;
+    }
+
     // Create navigator if needed
     if navigator_info.data_is_empty() {
         create_program_account::<Navigator>(
@@ -161,17 +226,35 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
         let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
         navigator.authority = *signer_info.key;
-        navigator.lifetime_dimensions_discovered = 1;
+        navigator.lifetime_dimensions_discovered = 0;
         navigator.lifetime_rewards_luxite = 0;
         navigator.lifetime_rewards_sol = 0;
         navigator.lifetime_deployed = 0;
         navigator.created_at = clock.unix_timestamp;
-    } else {
-        navigator_info.is_type::<Navigator>(&localuniverse_api::ID)?;
-        let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
-        navigator.lifetime_dimensions_discovered += 1;
+        navigator.referrer = Pubkey::default();
+        navigator.referral_rewards_sol = 0;
+        navigator.lifetime_referral_rewards_sol = 0;
+        navigator.referral_count = 0;
+        navigator.xp = 0;
+        navigator.rank = 0;
+        navigator.badges = 0;
+        navigator.deploy_streak = 0;
+        navigator.last_deploy_tick = 0;
+        navigator.lifetime_hits = 0;
+        navigator.season_id = 0;
+        navigator.season_deployed = 0;
+        navigator.season_mined = 0;
+        navigator.open_miners = 0;
+        navigator.tracks_miners = 1;
+        navigator.previous_season_deployed = 0;
+        navigator.previous_season_mined = 0;
     }
 
+    // Update navigator stats and rank
+    let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    navigator.record_discovery(richness);
+    update_rank(navigator, &clock);
+
     ScanEvent {
         disc: LocalUniverseEvent::Scan as u64,
         dimension_id,
@@ -183,3 +266,49 @@ pub fn process_scan(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     Ok(())
 }
+
+/// Rolls a richness score from a slot hash.
+pub fn roll_richness(hash: &[u8]) -> u32 {
+    // Use different parts of the hash for tier vs range
+    let tier_bytes = &hash[0..4];
+    let range_bytes = &hash[4..8];
+
+    let tier_roll = u32::from_le_bytes([
+        tier_bytes[0],
+        tier_bytes[1],
+        tier_bytes[2],
+        tier_bytes[3],
+    ]) % 10000;
+
+    let range_roll = u32::from_le_bytes([
+        range_bytes[0],
+        range_bytes[1],
+        range_bytes[2],
+        range_bytes[3],
+    ]);
+
+    // Richness determines hit chance (higher = harder to hit)
+    // Roll must be > richness to hit, so:
+    // - richness 900M = 10% hit chance
+    // - richness 500M = 50% hit chance  
+    // - richness 100M = 90% hit chance
+    if tier_roll < 8000 {
+        // 80% of dimensions: 750M-1B richness (0-25% hit chance)
+        750_000_000 + (range_roll % 250_000_001)
+    } else if tier_roll < 9500 {
+        // 15% of dimensions: 500M-750M richness (25-50% hit chance)
+        500_000_000 + (range_roll % 250_000_001)
+    } else if tier_roll < 9900 {
+        // 4% of dimensions: 250M-500M richness (50-75% hit chance)
+        250_000_000 + (range_roll % 250_000_001)
+    } else if tier_roll < 9990 {
+        // 0.9% of dimensions: 100M-250M richness (75-90% hit chance)
+        100_000_000 + (range_roll % 150_000_001)
+    } else if tier_roll < 9999 {
+        // 0.09% of dimensions: 20M-100M richness (90-98% hit chance)
+        20_000_000 + (range_roll % 80_000_001)
+    } else {
+        // 0.01% of dimensions: 0-20M richness (98-100% hit chance)
+        range_roll % 20_000_001
+    }
+}
@@ -0,0 +1,40 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    error::*,
+    state::*,
+};
+
+/// Splits a leading session key account off the trailing accounts. If present, it must belong
+/// to the authority, be signed for by the signer, be unexpired and grant the permission.
+pub fn session_key<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    authority: &Pubkey,
+    signer: &Pubkey,
+    permission: u64,
+    clock: &Clock,
+) -> Result<(Option<&'a AccountInfo<'info>>, &'a [AccountInfo<'info>]), ProgramError> {
+    let Some((session_info, rest)) = remaining_accounts.split_first() else {
+        return Ok((None, remaining_accounts));
+    };
+
+    if session_info.is_type::<SessionKey>(&localuniverse_api::ID).is_err() {
+        return Ok((None, remaining_accounts));
+    }
+
+    session_info.has_seeds(
+        &[SESSION, authority.as_ref(), signer.as_ref()],
+        &localuniverse_api::ID,
+    )?;
+
+    let session = session_info.as_account::<SessionKey>(&localuniverse_api::ID)?;
+    if session.is_expired(clock) {
+        return Err(LocalUniverseError::SessionExpired.into());
+    }
+    if !session.allows(permission) {
+        return Err(LocalUniverseError::NotAuthorized.into());
+    }
+
+    Ok((Some(session_info), rest))
+}
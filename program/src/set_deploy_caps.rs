@@ -0,0 +1,44 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the per-miner and per-excavation deploy caps and the LUXITE curve (admin only).
+pub fn process_set_deploy_caps(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetDeployCaps::try_from_bytes(data)?;
+    let max_miner_deploy = u64::from_le_bytes(args.max_miner_deploy);
+    let max_excavation_deploy = u64::from_le_bytes(args.max_excavation_deploy);
+    let luxite_curve = u64::from_le_bytes(args.luxite_curve);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(
+        Config::is_valid_deploy_cap(max_miner_deploy)
+            && Config::is_valid_deploy_cap(max_excavation_deploy),
+        "Deploy cap too low"
+    );
+    assert!(
+        luxite_curve == LUXITE_CURVE_LINEAR || luxite_curve == LUXITE_CURVE_SQRT,
+        "Invalid LUXITE curve"
+    );
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.max_miner_deploy = max_miner_deploy;
+    config.max_excavation_deploy = max_excavation_deploy;
+    config.luxite_curve = luxite_curve;
+
+    Ok(())
+}
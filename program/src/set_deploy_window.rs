@@ -0,0 +1,35 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the late-deploy cutoff and time-weighting decay (admin only).
+pub fn process_set_deploy_window(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetDeployWindow::try_from_bytes(data)?;
+    let deploy_cutoff_slots = u64::from_le_bytes(args.deploy_cutoff_slots);
+    let late_deploy_decay_bps = u64::from_le_bytes(args.late_deploy_decay_bps);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(deploy_cutoff_slots < TICK_DURATION_SLOTS, "Cutoff exceeds tick duration");
+    assert!(late_deploy_decay_bps <= DENOMINATOR_BPS, "Decay exceeds 100%");
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.deploy_cutoff_slots = deploy_cutoff_slots;
+    config.late_deploy_decay_bps = late_deploy_decay_bps;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Sets the key allowed to claim a miner's rewards and the wallet that receives them.
+/// The default pubkey clears either override.
+pub fn process_set_miner_delegate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetMinerDelegate::try_from_bytes(data)?;
+    let dimension_id = u64::from_le_bytes(args.dimension_id);
+    let delegate = Pubkey::new_from_array(args.delegate);
+    let recipient = Pubkey::new_from_array(args.recipient);
+
+    let [signer_info, miner_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate miner
+    let miner = miner_info
+        .is_writable()?
+        .has_seeds(
+            &[MINER, &dimension_id.to_le_bytes(), signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<Miner>(&localuniverse_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+
+    // Set overrides
+    miner.claim_delegate = delegate;
+    miner.recipient = recipient;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the miss rebate and, optionally, the LUXITE rate it is paid at (admin only).
+pub fn process_set_miss_rebate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetMissRebate::try_from_bytes(data)?;
+    let new_rebate_bps = u64::from_le_bytes(args.miss_rebate_bps);
+    let new_luxite_rate = u64::from_le_bytes(args.luxite_rate);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(new_rebate_bps <= MAX_MISS_REBATE_BPS, "Miss rebate too high");
+    assert!(new_luxite_rate <= MAX_MISS_REBATE_LUXITE_RATE, "Miss rebate LUXITE rate too high");
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.miss_rebate_bps = new_rebate_bps;
+    config.miss_rebate_luxite_rate = new_luxite_rate;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Changes the dimension a pool deploys to (operator only). The pool must be idle.
+pub fn process_set_pool_dimension(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetPoolDimension::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
+    let dimension_id = u64::from_le_bytes(args.dimension_id);
+
+    let [signer_info, pool_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let pool = pool_info
+        .is_writable()?
+        .has_seeds(
+            &[POOL, signer_info.key.as_ref(), &id.to_le_bytes()],
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<Pool>(&localuniverse_api::ID)?;
+
+    assert!(!pool.is_deployed(), "Pool must harvest first");
+
+    pool.dimension_id = dimension_id;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the referral share of deploy and scan fees (admin only).
+pub fn process_set_referral_fee(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetReferralFee::try_from_bytes(data)?;
+    let new_fee_bps = u64::from_le_bytes(args.referral_fee_bps);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(new_fee_bps <= DENOMINATOR_BPS, "Referral fee exceeds 100%");
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.referral_fee_bps = new_fee_bps;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    event::*,
+};
+
+/// Sets the referrer for the signer's navigator. Can only be set once.
+pub fn process_set_referrer(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    let [signer_info, navigator_info, referrer_navigator_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    navigator_info
+        .is_writable()?
+        .has_seeds(
+            &[NAVIGATOR, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    referrer_navigator_info
+        .is_type::<Navigator>(&localuniverse_api::ID)?
+        .is_writable()?;
+
+    system_program.is_program(&system_program::ID)?;
+
+    let referrer_navigator = referrer_navigator_info.as_account::<Navigator>(&localuniverse_api::ID)?;
+    let referrer = referrer_navigator.authority;
+
+    referrer_navigator_info.has_seeds(
+        &[NAVIGATOR, referrer.as_ref()],
+        &localuniverse_api::ID,
+    )?;
+
+    assert!(referrer != *signer_info.key, "Cannot refer yourself");
+    assert!(
+        referrer_navigator.referrer != *signer_info.key,
+        "Cannot refer your referrer"
+    );
+
+    // Create navigator if needed
+    if navigator_info.data_is_empty() {
+        create_program_account::<Navigator>(
+            navigator_info,
+            system_program,
+            signer_info,
+            &localuniverse_api::ID,
+            &[NAVIGATOR, signer_info.key.as_ref()],
+        )?;
+
+        let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+        navigator.authority = *signer_info.key;
+        navigator.lifetime_dimensions_discovered = 0;
+        navigator.lifetime_rewards_luxite = 0;
+        navigator.lifetime_rewards_sol = 0;
+        navigator.lifetime_deployed = 0;
+        navigator.created_at = clock.unix_timestamp;
+        navigator.referrer = Pubkey::default();
+        navigator.referral_rewards_sol = 0;
+        navigator.lifetime_referral_rewards_sol = 0;
+        navigator.referral_count = 0;
+        navigator.xp = 0;
+        navigator.rank = 0;
+        navigator.badges = 0;
+        navigator.deploy_streak = 0;
+        navigator.last_deploy_tick = 0;
+        navigator.lifetime_hits = 0;
+        navigator.season_id = 0;
+        navigator.season_deployed = 0;
+        navigator.season_mined = 0;
+        navigator.open_miners = 0;
+        navigator.tracks_miners = 1;
+        navigator.previous_season_deployed = 0;
+        navigator.previous_season_mined = 0;
+    } else {
+        navigator_info.is_type::<Navigator>(&localuniverse_api::ID)?;
+    }
+
+    let navigator = navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    assert!(!navigator.has_referrer(), "Referrer already set");
+    navigator.referrer = referrer;
+
+    let referrer_navigator = referrer_navigator_info.as_account_mut::<Navigator>(&localuniverse_api::ID)?;
+    referrer_navigator.referral_count += 1;
+
+    SetReferrerEvent {
+        disc: LocalUniverseEvent::SetReferrer as u64,
+        authority: *signer_info.key,
+        referrer,
+        ts: clock.unix_timestamp,
+    }
+    .log();
+
+    Ok(())
+}
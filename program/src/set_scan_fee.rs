@@ -17,6 +17,8 @@ pub fn process_set_scan_fee(accounts: &[AccountInfo], data: &[u8]) -> ProgramRes
 
     signer_info.is_signer()?;
 
+    assert!(new_fee <= MAX_SCAN_FEE, "Scan fee out of range");
+
     let config = config_info
         .as_account_mut::<Config>(&localuniverse_api::ID)?
         .assert_mut_err(
@@ -0,0 +1,34 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the curve boosting mining rewards by staked balance (admin only).
+pub fn process_set_stake_boost(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetStakeBoost::try_from_bytes(data)?;
+    let max_boost_bps = u64::from_le_bytes(args.max_boost_bps);
+    let half_balance = u64::from_le_bytes(args.half_balance);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(max_boost_bps <= MAX_STAKE_BOOST_BPS, "Boost exceeds maximum");
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.stake_boost_max_bps = max_boost_bps;
+    config.stake_boost_half_balance = half_balance;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+/// Sets the key allowed to claim a stake position's rewards and the wallet that receives them.
+/// The default pubkey clears either override.
+pub fn process_set_stake_delegate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetStakeDelegate::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
+    let delegate = Pubkey::new_from_array(args.delegate);
+    let recipient = Pubkey::new_from_array(args.recipient);
+
+    let [signer_info, stake_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate stake
+    let stake = stake_info
+        .is_writable()?
+        .has_seeds(
+            &stake_seeds(signer_info.key.as_ref(), &id.to_le_bytes()),
+            &localuniverse_api::ID,
+        )?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+
+    // Set overrides
+    stake.claim_delegate = delegate;
+    stake.recipient = recipient;
+
+    Ok(())
+}
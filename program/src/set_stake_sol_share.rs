@@ -0,0 +1,32 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the share of SOL revenue streamed to stakers instead of buybacks (admin only).
+pub fn process_set_stake_sol_share(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetStakeSOLShare::try_from_bytes(data)?;
+    let stake_sol_share_bps = u64::from_le_bytes(args.stake_sol_share_bps);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(stake_sol_share_bps <= DENOMINATOR_BPS, "Share exceeds 100%");
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.stake_sol_share_bps = stake_sol_share_bps;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Sets the cooldown between a stake withdrawal and its completion (admin only).
+pub fn process_set_unbonding_period(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = SetUnbondingPeriod::try_from_bytes(data)?;
+    let unbonding_period = i64::from_le_bytes(args.unbonding_period);
+
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    assert!(
+        (MIN_UNBONDING_PERIOD..=MAX_UNBONDING_PERIOD).contains(&unbonding_period),
+        "Unbonding period out of range"
+    );
+
+    let config = config_info
+        .as_account_mut::<Config>(&localuniverse_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    config.unbonding_period = unbonding_period;
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    event::*,
+};
+
+/// Settles an ended season, paying the LUXITE prize pool to the leaderboard.
+/// Each winner's wallet and LUXITE token account are passed as remaining account pairs in
+/// leaderboard order. Missing token accounts are created, funded by the signer. Prizes for
+/// empty leaderboard positions are returned to the treasury to fund later seasons.
+pub fn process_settle_season(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    if accounts.len() < 9 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, winner_infos) = accounts.split_at(9);
+
+    let [
+        signer_info,
+        grid_info,
+        season_info,
+        mint_info,
+        treasury_info,
+        treasury_tokens_info,
+        system_program,
+        token_program,
+        associated_token_program,
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let grid = grid_info
+        .has_seeds(&[GRID], &localuniverse_api::ID)?
+        .as_account::<Grid>(&localuniverse_api::ID)?;
+
+    season_info
+        .is_type::<Season>(&localuniverse_api::ID)?
+        .is_writable()?;
+
+    let season = season_info
+        .as_account_mut::<Season>(&localuniverse_api::ID)?
+        .assert_mut(|s| !s.is_settled())?
+        .assert_mut(|s| grid.tick_id >= s.settle_tick())?;
+
+    season_info.has_seeds(&[SEASON, &season.id.to_le_bytes()], &localuniverse_api::ID)?;
+
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    treasury_info
+        .is_type::<Treasury>(&localuniverse_api::ID)?
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?;
+
+    treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    let winners = season.winners();
+    if winner_infos.len() < winners * 2 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Pay winners
+    let mut paid = 0;
+    for (position, pair) in winner_infos.chunks_exact(2).take(winners).enumerate() {
+        let [wallet_info, winner_info] = pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let entry = season.leaderboard[position];
+        wallet_info.has_address(&entry.authority)?;
+        winner_info.is_writable()?;
+
+        let prize = season.prize(position);
+        if prize == 0 {
+            continue;
+        }
+
+        // Create the winner's token account so the prize is never forfeited
+        if winner_info.data_is_empty() {
+            create_associated_token_account(
+                signer_info,
+                wallet_info,
+                winner_info,
+                mint_info,
+                system_program,
+                token_program,
+                associated_token_program,
+            )?;
+        } else {
+            winner_info.as_associated_token_account(&entry.authority, mint_info.key)?;
+        }
+
+        transfer_signed(
+            treasury_info,
+            treasury_tokens_info,
+            winner_info,
+            token_program,
+            prize,
+            &[TREASURY],
+        )?;
+        paid += prize;
+    }
+
+    // Return prizes for empty positions to emissions
+    let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+    treasury.luxite_balance += season.prize_pool - paid;
+
+    season.settled = 1;
+
+    SettleSeasonEvent {
+        disc: LocalUniverseEvent::SettleSeason as u64,
+        season_id: season.id,
+        prize_pool: season.prize_pool,
+        paid,
+        winners: winners as u64,
+        ts: clock.unix_timestamp,
+    }
+    .log();
+
+    Ok(())
+}
@@ -34,6 +34,7 @@ pub fn process_tick(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     grid.tick_id += 1;
     grid.start_slot = clock.slot + 1;
     grid.end_slot = grid.start_slot + TICK_DURATION_SLOTS;
+    grid.epoch_id = grid.tick_id / EPOCH_DURATION_TICKS;
 
     // Emit tick event
     TickEvent {
@@ -0,0 +1,35 @@
+use steel::*;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+};
+
+/// Compounds the vault position's pending yield into its balance, moving the LUXITE from the
+/// treasury. Called before every vault deposit and redemption so the exchange rate is current.
+/// Receipts cannot be paid SOL, so the position's SOL revenue is recycled into buybacks.
+pub fn compound_vault<'info>(
+    stake: &mut Stake,
+    treasury: &mut Treasury,
+    clock: &Clock,
+    treasury_info: &AccountInfo<'info>,
+    treasury_tokens_info: &AccountInfo<'info>,
+    stake_tokens_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    let sol_rewards = stake.claim_sol(treasury);
+    treasury.sol_balance += sol_rewards;
+
+    let amount = stake.compound(clock, treasury);
+    if amount > 0 {
+        transfer_signed(
+            treasury_info,
+            treasury_tokens_info,
+            stake_tokens_info,
+            token_program,
+            amount,
+            &[TREASURY],
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+use crate::vault::*;
+
+/// Deposits LUXITE into the staking vault and mints receipt tokens at the current exchange rate.
+pub fn process_vault_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = VaultDeposit::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    let clock = Clock::get()?;
+
+    let [
+        signer_info,
+        mint_info,
+        sender_info,
+        vault_info,
+        vault_mint_info,
+        receipt_info,
+        stake_info,
+        stake_tokens_info,
+        treasury_info,
+        treasury_tokens_info,
+        system_program,
+        token_program,
+        associated_token_program,
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate mint
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    // Validate sender
+    let sender = sender_info
+        .is_writable()?
+        .as_associated_token_account(signer_info.key, &MINT_ADDRESS)?;
+
+    // Validate vault
+    let vault = vault_info
+        .is_writable()?
+        .has_seeds(&[VAULT], &localuniverse_api::ID)?
+        .as_account_mut::<Vault>(&localuniverse_api::ID)?;
+
+    // Validate receipt mint
+    let supply = vault_mint_info
+        .is_writable()?
+        .has_address(&vault.mint)?
+        .as_mint()?
+        .supply();
+
+    // Validate receipt
+    receipt_info.is_writable()?;
+
+    // Validate stake
+    let stake = stake_info
+        .is_writable()?
+        .has_address(&vault.stake)?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?;
+
+    // Validate stake tokens
+    stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+
+    // Validate treasury
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    // Validate treasury tokens
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(treasury_info.key, mint_info.key)?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Compound pending yield so the exchange rate is current
+    compound_vault(
+        stake,
+        treasury,
+        &clock,
+        treasury_info,
+        treasury_tokens_info,
+        stake_tokens_info,
+        token_program,
+    )?;
+
+    // Deposit into the vault's stake position
+    let assets = stake.balance;
+    let amount = stake.deposit(amount, &clock, treasury, &sender);
+    let shares = Vault::shares_for(amount, assets, supply);
+    assert!(shares > 0, "Deposit too small");
+
+    transfer(
+        signer_info,
+        sender_info,
+        stake_tokens_info,
+        token_program,
+        amount,
+    )?;
+
+    // Create receipt token account if needed
+    if receipt_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            signer_info,
+            receipt_info,
+            vault_mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        receipt_info.as_associated_token_account(signer_info.key, vault_mint_info.key)?;
+    }
+
+    // Mint receipt tokens
+    mint_to_signed(
+        vault_mint_info,
+        receipt_info,
+        vault_info,
+        token_program,
+        shares,
+        &[VAULT],
+    )?;
+
+    vault.total_deposited += amount;
+
+    // Safety check
+    let stake_tokens = stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    assert!(stake_tokens.amount() >= stake.balance + stake.unbonding_balance);
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+};
+
+use crate::vault::*;
+
+/// Burns receipt tokens and starts unbonding the LUXITE backing them from the staking vault,
+/// less a redemption fee that stays in the vault. The LUXITE earns no yield while unbonding
+/// and is withdrawn with CompleteRedeem once the unbonding period has passed.
+pub fn process_vault_redeem(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = VaultRedeem::try_from_bytes(data)?;
+    let shares = u64::from_le_bytes(args.shares);
+
+    let clock = Clock::get()?;
+
+    let [
+        signer_info,
+        mint_info,
+        config_info,
+        vault_info,
+        vault_mint_info,
+        receipt_info,
+        redemption_info,
+        stake_info,
+        stake_tokens_info,
+        treasury_info,
+        treasury_tokens_info,
+        system_program,
+        token_program,
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    signer_info.is_signer()?;
+
+    // Validate mint
+    mint_info.has_address(&MINT_ADDRESS)?;
+
+    // Validate config
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
+
+    // Validate vault
+    let vault = vault_info
+        .is_writable()?
+        .has_seeds(&[VAULT], &localuniverse_api::ID)?
+        .as_account_mut::<Vault>(&localuniverse_api::ID)?;
+
+    // Validate receipt mint
+    let supply = vault_mint_info
+        .is_writable()?
+        .has_address(&vault.mint)?
+        .as_mint()?
+        .supply();
+
+    // Validate receipt
+    let receipt = receipt_info
+        .is_writable()?
+        .as_associated_token_account(signer_info.key, vault_mint_info.key)?;
+
+    // Validate redemption
+    redemption_info
+        .is_writable()?
+        .has_seeds(
+            &[VAULT_REDEMPTION, signer_info.key.as_ref()],
+            &localuniverse_api::ID,
+        )?;
+
+    // Validate stake
+    let stake = stake_info
+        .is_writable()?
+        .has_address(&vault.stake)?
+        .as_account_mut::<Stake>(&localuniverse_api::ID)?;
+
+    // Validate stake tokens
+    stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+
+    // Validate treasury
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account_mut::<Treasury>(&localuniverse_api::ID)?;
+
+    // Validate treasury tokens
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(treasury_info.key, mint_info.key)?;
+
+    // Validate programs
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+
+    // Compound pending yield so the exchange rate is current
+    compound_vault(
+        stake,
+        treasury,
+        &clock,
+        treasury_info,
+        treasury_tokens_info,
+        stake_tokens_info,
+        token_program,
+    )?;
+
+    // Price the redemption
+    let shares = receipt.amount().min(shares);
+    let amount = Vault::assets_for(shares, stake.balance, supply);
+    let fee = Vault::redeem_fee(amount);
+    let amount = amount - fee;
+    assert!(amount > 0, "Redemption too small");
+
+    // Burn receipt tokens
+    burn(
+        receipt_info,
+        vault_mint_info,
+        signer_info,
+        token_program,
+        shares,
+    )?;
+
+    // Create redemption account if needed
+    if redemption_info.data_is_empty() {
+        create_program_account::<VaultRedemption>(
+            redemption_info,
+            system_program,
+            signer_info,
+            &localuniverse_api::ID,
+            &[VAULT_REDEMPTION, signer_info.key.as_ref()],
+        )?;
+
+        let redemption = redemption_info.as_account_mut::<VaultRedemption>(&localuniverse_api::ID)?;
+        redemption.authority = *signer_info.key;
+        redemption.amount = 0;
+        redemption.unbonding_at = 0;
+    }
+
+    // Move the amount into unbonding, restarting the redemption's unbonding period
+    let amount = stake.withdraw(amount, &clock, treasury, config.unbonding_period);
+    let redemption = redemption_info.as_account_mut::<VaultRedemption>(&localuniverse_api::ID)?;
+    redemption.amount += amount;
+    redemption.unbonding_at = clock.unix_timestamp + config.unbonding_period;
+
+    vault.total_redeemed += amount;
+    vault.total_fees += fee;
+
+    // Safety check
+    let stake_tokens = stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    assert!(stake_tokens.amount() >= stake.balance + stake.unbonding_balance);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use steel::*;
+use localuniverse_api::{
+    consts::*,
+    instruction::*,
+    state::*,
+    error::*,
+};
+
+/// Vetoes a governance proposal (admin only, during the bootstrap period).
+pub fn process_veto_proposal(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let args = VetoProposal::try_from_bytes(data)?;
+    let proposal_id = u64::from_le_bytes(args.proposal_id);
+
+    let clock = Clock::get()?;
+
+    let [signer_info, config_info, proposal_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            LocalUniverseError::NotAuthorized.into(),
+        )?;
+
+    let proposal = proposal_info
+        .is_writable()?
+        .has_seeds(&[PROPOSAL, &proposal_id.to_le_bytes()], &localuniverse_api::ID)?
+        .as_account_mut::<Proposal>(&localuniverse_api::ID)?;
+
+    assert!(config.can_veto(&clock), "Veto period has ended");
+    assert!(!proposal.is_closed(), "Proposal is closed");
+
+    proposal.vetoed = 1;
+
+    Ok(())
+}
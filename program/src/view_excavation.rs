@@ -0,0 +1,50 @@
+use steel::*;
+use solana_program::program::set_return_data;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    view::*,
+};
+
+/// Returns an excavation's hit probability at its current total deployed, and its roll and
+/// outcome once processed, as return data. Richness is taken at the drill's current depth,
+/// which is the depth an unprocessed excavation rolls at. Writes nothing; call it with
+/// simulateTransaction.
+pub fn process_view_excavation(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [dimension_info, drill_info, excavation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let excavation = excavation_info.as_account::<Excavation>(&localuniverse_api::ID)?;
+    let dimension_id = excavation.dimension_id;
+    excavation_info.has_seeds(
+        &[EXCAVATION, &dimension_id.to_le_bytes(), &excavation.id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    let dimension = dimension_info
+        .has_seeds(&[DIMENSION, &dimension_id.to_le_bytes()], &localuniverse_api::ID)?
+        .as_account::<Dimension>(&localuniverse_api::ID)?;
+
+    let drill = drill_info
+        .has_seeds(&[DRILL, &dimension_id.to_le_bytes()], &localuniverse_api::ID)?
+        .as_account::<Drill>(&localuniverse_api::ID)?;
+
+    let richness = dimension.richness_at(drill.depth);
+
+    let view = ExcavationView {
+        dimension_id,
+        tick_id: excavation.id,
+        total_deployed: excavation.total_deployed,
+        richness,
+        effective_richness: excavation.effective_richness(richness),
+        hit_probability_bps: excavation.hit_probability_bps(richness),
+        processed: excavation.is_processed() as u64,
+        roll: excavation.roll().unwrap_or(0),
+        did_hit: excavation.did_hit,
+    };
+    set_return_data(view.to_bytes());
+
+    Ok(())
+}
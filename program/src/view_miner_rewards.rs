@@ -0,0 +1,104 @@
+use steel::*;
+use solana_program::program::set_return_data;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    view::*,
+};
+
+/// Returns a miner's claimable rewards as return data, including what a checkpoint of its last
+/// excavation would credit. Writes nothing; call it with simulateTransaction. Pass a stake
+/// position of the miner's authority as a trailing account to include the stake boost.
+pub fn process_view_miner_rewards(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    if accounts.len() < 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (accounts, remaining_accounts) = accounts.split_at(5);
+
+    let [config_info, grid_info, treasury_info, miner_info, excavation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
+
+    let grid = grid_info
+        .has_seeds(&[GRID], &localuniverse_api::ID)?
+        .as_account::<Grid>(&localuniverse_api::ID)?;
+
+    let treasury = treasury_info
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account::<Treasury>(&localuniverse_api::ID)?;
+
+    let miner = miner_info.as_account::<Miner>(&localuniverse_api::ID)?;
+    miner_info.has_seeds(
+        &[MINER, &miner.dimension_id.to_le_bytes(), miner.authority.as_ref()],
+        &localuniverse_api::ID,
+    )?;
+
+    excavation_info.has_seeds(
+        &[EXCAVATION, &miner.dimension_id.to_le_bytes(), &miner.excavation_id.to_le_bytes()],
+        &localuniverse_api::ID,
+    )?;
+
+    // Optional stake position of the miner's authority
+    let staked = match remaining_accounts.first() {
+        Some(stake_info) => {
+            stake_info
+                .as_account::<Stake>(&localuniverse_api::ID)?
+                .assert(|s| s.authority == miner.authority)?
+                .balance
+        }
+        None => 0,
+    };
+
+    // Apply the checkpoint and claim to copies, mirroring process_checkpoint
+    let mut miner = *miner;
+    let mut treasury = *treasury;
+    let mut status = CHECKPOINT_STATUS_NONE;
+    let mut checkpoint = CheckpointRewards::default();
+
+    if miner.checkpoint_id != miner.excavation_id {
+        status = if excavation_info.data_is_empty() {
+            CHECKPOINT_STATUS_FORFEITED
+        } else {
+            let excavation = excavation_info.as_account::<Excavation>(&localuniverse_api::ID)?;
+            let is_stale = !excavation.is_processed() && excavation.id + 1 < grid.tick_id;
+            if is_stale {
+                CHECKPOINT_STATUS_FORFEITED
+            } else if excavation.id == grid.tick_id || !excavation.is_processed() {
+                CHECKPOINT_STATUS_PENDING
+            } else if clock.slot >= excavation.expires_at {
+                CHECKPOINT_STATUS_FORFEITED
+            } else {
+                checkpoint =
+                    miner.checkpoint_rewards(excavation, config, treasury.luxite_balance, staked);
+                CHECKPOINT_STATUS_READY
+            }
+        };
+    }
+
+    miner.update_rewards(&treasury);
+    miner.rewards_sol += checkpoint.sol;
+    miner.rewards_luxite += checkpoint.luxite;
+    treasury.total_unclaimed += checkpoint.luxite;
+
+    let view = MinerRewardsView {
+        dimension_id: miner.dimension_id,
+        excavation_id: miner.excavation_id,
+        status,
+        checkpoint_sol: checkpoint.sol,
+        checkpoint_luxite: checkpoint.luxite,
+        rewards_sol: miner.rewards_sol,
+        rewards_luxite: miner.rewards_luxite,
+        refined_luxite: miner.refined_luxite,
+        claimable_luxite: miner.claim_luxite(&clock, &mut treasury),
+    };
+    set_return_data(view.to_bytes());
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use steel::*;
+use solana_program::program::set_return_data;
+
+use localuniverse_api::{
+    consts::*,
+    state::*,
+    view::*,
+};
+
+/// Returns a stake position's pending yield as return data. Writes nothing; call it with
+/// simulateTransaction.
+pub fn process_view_stake_yield(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [treasury_info, stake_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let treasury = treasury_info
+        .has_seeds(&[TREASURY], &localuniverse_api::ID)?
+        .as_account::<Treasury>(&localuniverse_api::ID)?;
+
+    let stake = stake_info.as_account::<Stake>(&localuniverse_api::ID)?;
+    stake_info.has_seeds(
+        &stake_seeds(stake.authority.as_ref(), &stake.id.to_le_bytes()),
+        &localuniverse_api::ID,
+    )?;
+
+    // Accrue yield on a copy
+    let mut stake = *stake;
+    stake.update_rewards(treasury);
+
+    let view = StakeYieldView {
+        id: stake.id,
+        balance: stake.balance,
+        weighted_balance: stake.weighted_balance,
+        rewards: stake.rewards,
+        sol_rewards: stake.sol_rewards,
+        unbonding_balance: stake.unbonding_balance,
+    };
+    set_return_data(view.to_bytes());
+
+    Ok(())
+}
@@ -3,85 +3,49 @@ use localuniverse_api::{
     consts::*,
     instruction::*,
     state::*,
+    error::*,
 };
 
-/// Withdraws LUXITE from the staking contract.
+/// Withdraws LUXITE from the staking contract. The amount stops earning yield and unbonds
+/// until it can be transferred out with CompleteWithdraw.
 pub fn process_withdraw(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let args = Withdraw::try_from_bytes(data)?;
+    let id = u64::from_le_bytes(args.id);
     let amount = u64::from_le_bytes(args.amount);
 
     let clock = Clock::get()?;
 
-    let [
-        signer_info,
-        mint_info,
-        recipient_info,
-        stake_info,
-        stake_tokens_info,
-        treasury_info,
-        system_program,
-        token_program,
-        associated_token_program,
-    ] = accounts else {
+    let [signer_info, config_info, stake_info, treasury_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     // Validate signer
     signer_info.is_signer()?;
 
-    // Validate mint
-    mint_info.has_address(&MINT_ADDRESS)?;
-
-    // Validate recipient
-    recipient_info.is_writable()?;
+    // Validate config
+    let config = config_info
+        .has_seeds(&[CONFIG], &localuniverse_api::ID)?
+        .as_account::<Config>(&localuniverse_api::ID)?;
 
     // Validate stake
     let stake = stake_info
+        .has_seeds(
+            &stake_seeds(signer_info.key.as_ref(), &id.to_le_bytes()),
+            &localuniverse_api::ID,
+        )?
         .as_account_mut::<Stake>(&localuniverse_api::ID)?
         .assert_mut(|s| s.authority == *signer_info.key)?;
 
-    // Validate stake tokens
-    stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-
     // Validate treasury
     let treasury = treasury_info.as_account_mut::<Treasury>(&localuniverse_api::ID)?;
 
-    // Validate programs
-    system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
-    associated_token_program.is_program(&spl_associated_token_account::ID)?;
-
-    // Create recipient token account if needed
-    if recipient_info.data_is_empty() {
-        create_associated_token_account(
-            signer_info,
-            signer_info,
-            recipient_info,
-            mint_info,
-            system_program,
-            token_program,
-            associated_token_program,
-        )?;
-    } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+    // Locked positions, and positions voting on an open proposal, cannot be withdrawn
+    if stake.is_locked(&clock) || stake.is_vote_locked(&clock) {
+        return Err(LocalUniverseError::StakeLocked.into());
     }
 
-    // Withdraw from stake account
-    let amount = stake.withdraw(amount, &clock, treasury);
-
-    // Transfer LUXITE to recipient
-    transfer_signed(
-        stake_info,
-        stake_tokens_info,
-        recipient_info,
-        token_program,
-        amount,
-        &[STAKE, &stake.authority.to_bytes()],
-    )?;
-
-    // Safety check
-    let stake_tokens = stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    // Move the amount into unbonding
+    stake.withdraw(amount, &clock, treasury, config.unbonding_period);
 
     Ok(())
 }